@@ -0,0 +1,11 @@
+fn main() {
+    #[cfg(feature = "cxx")]
+    {
+        println!("cargo:rerun-if-changed=src/cxx_ffi.cc");
+        cc::Build::new()
+            .cpp(true)
+            .std("c++20")
+            .file("src/cxx_ffi.cc")
+            .compile("moveref-cxx");
+    }
+}
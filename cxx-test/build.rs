@@ -0,0 +1,9 @@
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=src/widget.h");
+    println!("cargo:rerun-if-changed=src/widget.cc");
+    cxx_build::bridge("src/lib.rs")
+        .std("c++20")
+        .file("src/widget.cc")
+        .compile("moveref-cxx-test");
+}
@@ -0,0 +1,43 @@
+#![deny(clippy::all)]
+#![deny(clippy::cargo)]
+#![deny(clippy::implicit_return)]
+#![deny(clippy::nursery)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::missing_docs_in_private_items)]
+#![allow(clippy::needless_return)]
+
+//! Bridged C++ types for testing emplacement of opaque types in the `moveref` crate.
+
+/// Bridged C++ types for testing emplacement of opaque types.
+#[allow(clippy::implicit_return, clippy::missing_safety_doc)]
+#[cxx::bridge(namespace = "moveref::test")]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("moveref-cxx-test/src/widget.h");
+
+        /// A C++ class which is opaque (and zero-sized) on the Rust side.
+        type Widget;
+
+        /// Return the value the widget was constructed with.
+        fn value(self: &Widget) -> u64;
+
+        /// Return `sizeof(Widget)`.
+        #[must_use]
+        fn widget_size() -> usize;
+
+        /// Return `alignof(Widget)`.
+        #[must_use]
+        fn widget_align() -> usize;
+
+        /// Construct a widget at `out` which increments `*drops` when destroyed.
+        ///
+        /// # Safety
+        ///
+        /// - `out` must be valid for writes of a `Widget` and suitably aligned
+        /// - `drops` must remain valid for writes until the widget is destroyed
+        unsafe fn widget_new(out: *mut Widget, value: u64, drops: *mut usize);
+    }
+
+    impl UniquePtr<Widget> {
+    }
+}
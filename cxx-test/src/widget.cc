@@ -0,0 +1,24 @@
+#include "moveref-cxx-test/src/widget.h"
+
+#include <new>
+
+namespace moveref {
+namespace test {
+
+Widget::Widget(std::uint64_t value, std::size_t *drops) noexcept
+    : value_(value), drops_(drops), padding_{} {}
+
+Widget::~Widget() { *drops_ += 1; }
+
+std::uint64_t Widget::value() const noexcept { return value_; }
+
+std::size_t widget_size() noexcept { return sizeof(Widget); }
+
+std::size_t widget_align() noexcept { return alignof(Widget); }
+
+void widget_new(Widget *self, std::uint64_t value, std::size_t *drops) noexcept {
+  new (self) Widget(value, drops);
+}
+
+} // namespace test
+} // namespace moveref
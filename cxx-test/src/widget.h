@@ -0,0 +1,27 @@
+#pragma once
+
+#include <cstddef>
+#include <cstdint>
+
+namespace moveref {
+namespace test {
+
+class Widget {
+public:
+  Widget(std::uint64_t value, std::size_t *drops) noexcept;
+  ~Widget();
+
+  std::uint64_t value() const noexcept;
+
+private:
+  std::uint64_t value_;
+  std::size_t *drops_;
+  std::uint64_t padding_[2];
+};
+
+std::size_t widget_size() noexcept;
+std::size_t widget_align() noexcept;
+void widget_new(Widget *self, std::uint64_t value, std::size_t *drops) noexcept;
+
+} // namespace test
+} // namespace moveref
@@ -0,0 +1,184 @@
+#![deny(clippy::all)]
+#![deny(clippy::cargo)]
+#![deny(clippy::implicit_return)]
+#![deny(clippy::nursery)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::missing_docs_in_private_items)]
+#![allow(clippy::needless_return)]
+
+//! Procedural macros for the `moveref` crate.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse_macro_input,
+    spanned::Spanned,
+    FnArg,
+    GenericArgument,
+    ItemFn,
+    PathArguments,
+    ReturnType,
+    Type,
+};
+
+/// Attribute macro turning a constructor function into one returning `impl New` or `impl TryNew`.
+///
+/// The annotated function must be an `unsafe fn` whose first parameter is the storage to
+/// initialize, of type `Pin<&mut MaybeUninit<T>>`. Being `unsafe` marks that the body promises to
+/// fully initialize that storage before returning normally.
+///
+/// The function is rewritten into a safe function taking the remaining parameters and returning
+/// `impl New<Output = T>`, which runs the original body once storage is provided. If the function
+/// returns `Result<(), E>`, it instead returns `impl TryNew<Output = T, Error = E>`, and the body
+/// only needs to initialize the storage when it returns `Ok(())`.
+///
+/// Parameters which borrow must use named lifetimes, which are captured by the returned value.
+#[proc_macro_attribute]
+pub fn ctor(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        let attr = proc_macro2::TokenStream::from(attr);
+        let err = syn::Error::new(attr.span(), "`#[ctor]` does not take arguments");
+        return err.to_compile_error().into();
+    }
+    let item = parse_macro_input!(item as ItemFn);
+    match expand(item.clone()) {
+        | Ok(tokens) => return tokens.into(),
+        | Err(err) => {
+            // Keep the original item so that the error is not buried under unresolved uses of it.
+            let err = err.to_compile_error();
+            return quote!(#err #item).into();
+        },
+    }
+}
+
+/// Expand a constructor function into its `impl New` or `impl TryNew` returning form.
+fn expand(item: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let ItemFn {
+        attrs,
+        vis,
+        mut sig,
+        block,
+    } = item;
+
+    if sig.unsafety.is_none() {
+        let msg =
+            "`#[ctor]` functions must be `unsafe fn`, since they must initialize their storage";
+        return Err(syn::Error::new(sig.fn_token.span, msg));
+    }
+    if let Some(asyncness) = sig.asyncness {
+        let msg = "`#[ctor]` functions cannot be `async`";
+        return Err(syn::Error::new(asyncness.span, msg));
+    }
+
+    let mut inputs = core::mem::take(&mut sig.inputs).into_iter();
+    let this = match inputs.next() {
+        | Some(FnArg::Typed(this)) => this,
+        | Some(FnArg::Receiver(receiver)) => {
+            let msg = "the first parameter of a `#[ctor]` function must be its storage, not `self`";
+            return Err(syn::Error::new(receiver.span(), msg));
+        },
+        | None => {
+            let msg = "`#[ctor]` functions must take a first parameter of type `Pin<&mut MaybeUninit<T>>`";
+            return Err(syn::Error::new(sig.paren_token.span.join(), msg));
+        },
+    };
+    sig.inputs = inputs.collect();
+    let output = storage_output(&this.ty)?;
+
+    let lifetimes = sig
+        .generics
+        .lifetimes()
+        .map(|param| return param.lifetime.clone());
+    let lifetimes = lifetimes.collect::<Vec<_>>();
+    let constructor = match &sig.output {
+        | ReturnType::Default => {
+            sig.output = syn::parse_quote! {
+                -> impl ::moveref::New<Output = #output> #(+ #lifetimes)*
+            };
+            quote! {
+                ::moveref::new::by_raw(move |#this| #block)
+            }
+        },
+        | ReturnType::Type(_, ty) => {
+            let ty = ty.clone();
+            let error = result_error(&ty)?;
+            sig.output = syn::parse_quote! {
+                -> impl ::moveref::new::TryNew<Output = #output, Error = #error> #(+ #lifetimes)*
+            };
+            quote! {
+                ::moveref::new::try_by_raw(move |#this| -> #ty #block)
+            }
+        },
+    };
+    sig.unsafety = None;
+
+    return Ok(quote! {
+        #(#attrs)*
+        #vis #sig {
+            unsafe {
+                return #constructor;
+            }
+        }
+    });
+}
+
+/// Extract `T` from a storage parameter type `Pin<&mut MaybeUninit<T>>`.
+fn storage_output(ty: &Type) -> syn::Result<&Type> {
+    let msg =
+        "the first parameter of a `#[ctor]` function must have type `Pin<&mut MaybeUninit<T>>`";
+    let err = || return syn::Error::new(ty.span(), msg);
+    let pointer = single_generic_argument(ty, "Pin").ok_or_else(err)?;
+    let Type::Reference(reference) = pointer else {
+        return Err(err());
+    };
+    if reference.mutability.is_none() {
+        return Err(err());
+    }
+    return single_generic_argument(&reference.elem, "MaybeUninit").ok_or_else(err);
+}
+
+/// Extract `E` from a return type `Result<(), E>`.
+fn result_error(ty: &Type) -> syn::Result<&Type> {
+    let msg = "`#[ctor]` functions must return either nothing or `Result<(), E>`";
+    let err = || return syn::Error::new(ty.span(), msg);
+    let Type::Path(path) = ty else {
+        return Err(err());
+    };
+    let segment = path.path.segments.last().ok_or_else(err)?;
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return Err(err());
+    };
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        | GenericArgument::Type(ty) => return Some(ty),
+        | _ => return None,
+    });
+    match (
+        segment.ident == "Result",
+        types.next(),
+        types.next(),
+        types.next(),
+    ) {
+        | (true, Some(Type::Tuple(unit)), Some(error), None) if unit.elems.is_empty() => {
+            return Ok(error);
+        },
+        | _ => return Err(err()),
+    }
+}
+
+/// Extract `T` from a type `Name<T>`, where `Name` is the last segment of a path.
+fn single_generic_argument<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != name {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.iter().collect::<Vec<_>>().as_slice() {
+        | [GenericArgument::Type(ty)] => return Some(ty),
+        | _ => return None,
+    }
+}
@@ -0,0 +1,92 @@
+#include <cstddef>
+#include <cstdint>
+#include <new>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace {
+
+bool is_overaligned(std::size_t align) noexcept {
+  return align > __STDCPP_DEFAULT_NEW_ALIGNMENT__;
+}
+
+static_assert(sizeof(std::string) <= 8 * sizeof(void *),
+              "std::string does not fit `InlineCxxString`");
+static_assert(alignof(std::string) <= alignof(void *),
+              "std::string is overaligned for `InlineCxxString`");
+
+} // namespace
+
+extern "C" {
+
+void *moveref$cxx$operator_new(std::size_t size, std::size_t align) noexcept {
+  if (is_overaligned(align)) {
+    return ::operator new(size, std::align_val_t(align), std::nothrow);
+  }
+  return ::operator new(size, std::nothrow);
+}
+
+void moveref$cxx$operator_delete(void *ptr, std::size_t align) noexcept {
+  if (is_overaligned(align)) {
+    ::operator delete(ptr, std::align_val_t(align));
+  } else {
+    ::operator delete(ptr);
+  }
+}
+
+void moveref$cxx$string$new(std::string *self, const char *ptr,
+                            std::size_t len) noexcept {
+  new (self) std::string(ptr, len);
+}
+
+void moveref$cxx$string$copy_new(std::string *self,
+                                 const std::string *src) noexcept {
+  new (self) std::string(*src);
+}
+
+void moveref$cxx$string$move_new(std::string *self, std::string *src) noexcept {
+  new (self) std::string(std::move(*src));
+}
+
+void moveref$cxx$string$drop(std::string *self) noexcept {
+  self->~basic_string();
+}
+
+#define MOVEREF_CXX_VECTOR(RUST, CXX)                                          \
+  static_assert(sizeof(std::vector<CXX>) <= 4 * sizeof(void *),                \
+                "std::vector does not fit `InlineCxxVector`");                 \
+  static_assert(alignof(std::vector<CXX>) <= alignof(void *),                  \
+                "std::vector is overaligned for `InlineCxxVector`");           \
+  void moveref$cxx$vector$##RUST##$new(std::vector<CXX> *self, const CXX *ptr, \
+                                       std::size_t len) noexcept {             \
+    new (self) std::vector<CXX>(ptr, ptr + len);                               \
+  }                                                                            \
+  void moveref$cxx$vector$##RUST##$copy_new(                                   \
+      std::vector<CXX> *self, const std::vector<CXX> *src) noexcept {          \
+    new (self) std::vector<CXX>(*src);                                         \
+  }                                                                            \
+  void moveref$cxx$vector$##RUST##$move_new(std::vector<CXX> *self,            \
+                                            std::vector<CXX> *src) noexcept {  \
+    new (self) std::vector<CXX>(std::move(*src));                              \
+  }                                                                            \
+  void moveref$cxx$vector$##RUST##$drop(std::vector<CXX> *self) noexcept {     \
+    self->~vector();                                                           \
+  }
+
+MOVEREF_CXX_VECTOR(u8, std::uint8_t)
+MOVEREF_CXX_VECTOR(u16, std::uint16_t)
+MOVEREF_CXX_VECTOR(u32, std::uint32_t)
+MOVEREF_CXX_VECTOR(u64, std::uint64_t)
+MOVEREF_CXX_VECTOR(usize, std::size_t)
+MOVEREF_CXX_VECTOR(i8, std::int8_t)
+MOVEREF_CXX_VECTOR(i16, std::int16_t)
+MOVEREF_CXX_VECTOR(i32, std::int32_t)
+MOVEREF_CXX_VECTOR(i64, std::int64_t)
+MOVEREF_CXX_VECTOR(isize, std::ptrdiff_t)
+MOVEREF_CXX_VECTOR(f32, float)
+MOVEREF_CXX_VECTOR(f64, double)
+
+#undef MOVEREF_CXX_VECTOR
+
+} // extern "C"
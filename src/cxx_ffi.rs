@@ -0,0 +1,46 @@
+use core::{alloc::Layout, ffi::c_void, ptr::NonNull};
+
+extern "C" {
+    /// Allocate storage for `size` bytes aligned to `align` with the C++ `operator new`.
+    #[link_name = "moveref$cxx$operator_new"]
+    fn cxx_operator_new(size: usize, align: usize) -> *mut c_void;
+    /// Deallocate storage aligned to `align` with the C++ `operator delete`.
+    #[link_name = "moveref$cxx$operator_delete"]
+    fn cxx_operator_delete(ptr: *mut c_void, align: usize);
+}
+
+/// Allocate storage for `layout` with the C++ `operator new`, returning `None` on failure.
+///
+/// The storage is allocated such that a C++ `delete` expression for a type with the same layout
+/// deallocates it with the matching `operator delete`.
+#[inline]
+pub(crate) fn operator_new(layout: Layout) -> Option<NonNull<u8>> {
+    let ptr = unsafe { cxx_operator_new(layout.size(), layout.align()) };
+    return NonNull::new(ptr.cast::<u8>());
+}
+
+/// Deallocate storage allocated with [`operator_new`].
+///
+/// # Safety
+///
+/// - `ptr` must have been returned by [`operator_new`] with the same `layout`
+/// - `ptr` must not have been deallocated already
+#[inline]
+pub(crate) unsafe fn operator_delete(ptr: NonNull<u8>, layout: Layout) {
+    cxx_operator_delete(ptr.as_ptr().cast::<c_void>(), layout.align());
+}
+
+extern "C" {
+    /// Construct a `std::string` at `this` holding a copy of the `len` bytes at `ptr`.
+    #[link_name = "moveref$cxx$string$new"]
+    pub(crate) fn string_new(this: *mut c_void, ptr: *const u8, len: usize);
+    /// Copy-construct a `std::string` at `this` from the one at `src`.
+    #[link_name = "moveref$cxx$string$copy_new"]
+    pub(crate) fn string_copy_new(this: *mut c_void, src: *const c_void);
+    /// Move-construct a `std::string` at `this` from the one at `src`.
+    #[link_name = "moveref$cxx$string$move_new"]
+    pub(crate) fn string_move_new(this: *mut c_void, src: *mut c_void);
+    /// Destroy the `std::string` at `this`.
+    #[link_name = "moveref$cxx$string$drop"]
+    pub(crate) fn string_drop(this: *mut c_void);
+}
@@ -0,0 +1,290 @@
+use core::{
+    ffi::c_void,
+    marker::{PhantomData, PhantomPinned},
+    mem::MaybeUninit,
+    ops::Deref,
+    pin::Pin,
+};
+
+use cxx::{vector::VectorElement, CxxString, CxxVector};
+
+use crate::{
+    cxx_ffi,
+    move_ref::MoveRef,
+    new::{CopyNew, MoveNew},
+};
+
+/// A C++ `std::string` stored inline, which dereferences to a [`CxxString`].
+///
+/// Unlike the string created by `cxx::let_cxx_string!`, an [`InlineCxxString`] is constructed by a
+/// [`New`](crate::New) initializer (see [`new::cxx_string`](crate::new::cxx_string)), so it can be
+/// placed with [`bind!`](crate::bind) or [`Emplace`](crate::Emplace) like any other value, and it
+/// is moved and copied with the C++ move and copy constructors.
+#[repr(C)]
+pub struct InlineCxxString {
+    /// Storage for the `std::string`, which is checked to fit when the C++ library is built.
+    space: MaybeUninit<[usize; 8]>,
+    /// Marker making the string `!Unpin`, since a `std::string` may point into itself.
+    _pinned: PhantomPinned,
+}
+
+impl InlineCxxString {
+    /// Return the pinned [`CxxString`] for mutation.
+    #[must_use]
+    #[inline]
+    pub fn pin_mut(self: Pin<&mut Self>) -> Pin<&mut CxxString> {
+        unsafe {
+            let this = self.get_unchecked_mut();
+            let ptr = this.space.as_mut_ptr().cast::<CxxString>();
+            return Pin::new_unchecked(&mut *ptr);
+        }
+    }
+}
+
+impl Deref for InlineCxxString {
+    type Target = CxxString;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        return unsafe { &*self.space.as_ptr().cast::<CxxString>() };
+    }
+}
+
+impl Drop for InlineCxxString {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { cxx_ffi::string_drop(self.space.as_mut_ptr().cast()) };
+    }
+}
+
+impl MoveNew for InlineCxxString {
+    #[inline]
+    unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+        let mut src = Pin::into_inner_unchecked(src);
+        let dst = Pin::into_inner_unchecked(dst);
+        // The moved-from string is destroyed when `src` is dropped.
+        cxx_ffi::string_move_new(dst.as_mut_ptr().cast(), src.as_mut_ptr().cast());
+    }
+}
+
+impl CopyNew for InlineCxxString {
+    #[inline]
+    unsafe fn copy_new(src: &Self, dst: Pin<&mut MaybeUninit<Self>>) {
+        let dst = Pin::into_inner_unchecked(dst);
+        cxx_ffi::string_copy_new(dst.as_mut_ptr().cast(), core::ptr::from_ref(src).cast());
+    }
+}
+
+/// A C++ `std::vector<T>` stored inline, which dereferences to a [`CxxVector<T>`].
+///
+/// This is the vector counterpart of [`InlineCxxString`], constructed by
+/// [`new::cxx_vector`](crate::new::cxx_vector).
+#[repr(C)]
+pub struct InlineCxxVector<T: InlineCxxVectorElement> {
+    /// Storage for the `std::vector<T>`, which is checked to fit when the C++ library is built.
+    space: MaybeUninit<[usize; 4]>,
+    /// Marker for the element type.
+    _elements: PhantomData<[T]>,
+    /// Marker making the vector `!Unpin`, matching [`CxxVector<T>`].
+    _pinned: PhantomPinned,
+}
+
+impl<T: InlineCxxVectorElement> InlineCxxVector<T> {
+    /// Return the pinned [`CxxVector<T>`] for mutation.
+    #[must_use]
+    #[inline]
+    pub fn pin_mut(self: Pin<&mut Self>) -> Pin<&mut CxxVector<T>> {
+        unsafe {
+            let this = self.get_unchecked_mut();
+            let ptr = this.space.as_mut_ptr().cast::<CxxVector<T>>();
+            return Pin::new_unchecked(&mut *ptr);
+        }
+    }
+}
+
+impl<T: InlineCxxVectorElement> Deref for InlineCxxVector<T> {
+    type Target = CxxVector<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        return unsafe { &*self.space.as_ptr().cast::<CxxVector<T>>() };
+    }
+}
+
+impl<T: InlineCxxVectorElement> Drop for InlineCxxVector<T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { T::__drop(self.space.as_mut_ptr().cast()) };
+    }
+}
+
+impl<T: InlineCxxVectorElement> MoveNew for InlineCxxVector<T> {
+    #[inline]
+    unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+        let mut src = Pin::into_inner_unchecked(src);
+        let dst = Pin::into_inner_unchecked(dst);
+        // The moved-from vector is destroyed when `src` is dropped.
+        T::__move_new(dst.as_mut_ptr().cast(), src.as_mut_ptr().cast());
+    }
+}
+
+impl<T: InlineCxxVectorElement> CopyNew for InlineCxxVector<T> {
+    #[inline]
+    unsafe fn copy_new(src: &Self, dst: Pin<&mut MaybeUninit<Self>>) {
+        let dst = Pin::into_inner_unchecked(dst);
+        T::__copy_new(dst.as_mut_ptr().cast(), core::ptr::from_ref(src).cast());
+    }
+}
+
+/// Private module for sealing [`InlineCxxVectorElement`].
+mod private {
+    /// Sealing trait for [`super::InlineCxxVectorElement`].
+    pub trait Sealed {}
+}
+
+/// Element types of an [`InlineCxxVector`], i.e., the primitive element types of [`CxxVector`].
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait InlineCxxVectorElement: VectorElement + Copy + private::Sealed {
+    /// Construct a `std::vector` at `this` holding a copy of the `len` elements at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// - `this` must be suitable uninitialized storage for the vector
+    /// - `ptr` must be valid for reads of `len` elements
+    #[doc(hidden)]
+    unsafe fn __new(this: *mut c_void, ptr: *const Self, len: usize);
+
+    /// Copy-construct a `std::vector` at `this` from the one at `src`.
+    ///
+    /// # Safety
+    ///
+    /// - `this` must be suitable uninitialized storage for the vector
+    /// - `src` must point to a live vector
+    #[doc(hidden)]
+    unsafe fn __copy_new(this: *mut c_void, src: *const c_void);
+
+    /// Move-construct a `std::vector` at `this` from the one at `src`.
+    ///
+    /// # Safety
+    ///
+    /// - `this` must be suitable uninitialized storage for the vector
+    /// - `src` must point to a live vector, which is left in its moved-from state
+    #[doc(hidden)]
+    unsafe fn __move_new(this: *mut c_void, src: *mut c_void);
+
+    /// Destroy the `std::vector` at `this`.
+    ///
+    /// # Safety
+    ///
+    /// - `this` must point to a live vector, which must not be used afterward
+    #[doc(hidden)]
+    unsafe fn __drop(this: *mut c_void);
+}
+
+/// Implement [`InlineCxxVectorElement`] for primitive types using the C++ support library.
+macro_rules! inline_cxx_vector_element {
+    ($($ty:ident),* $(,)?) => {$(
+        impl private::Sealed for $ty {}
+
+        impl InlineCxxVectorElement for $ty {
+            #[inline]
+            unsafe fn __new(this: *mut c_void, ptr: *const Self, len: usize) {
+                extern "C" {
+                    #[link_name = concat!("moveref$cxx$vector$", stringify!($ty), "$new")]
+                    fn __new(this: *mut c_void, ptr: *const $ty, len: usize);
+                }
+                __new(this, ptr, len);
+            }
+
+            #[inline]
+            unsafe fn __copy_new(this: *mut c_void, src: *const c_void) {
+                extern "C" {
+                    #[link_name = concat!("moveref$cxx$vector$", stringify!($ty), "$copy_new")]
+                    fn __copy_new(this: *mut c_void, src: *const c_void);
+                }
+                __copy_new(this, src);
+            }
+
+            #[inline]
+            unsafe fn __move_new(this: *mut c_void, src: *mut c_void) {
+                extern "C" {
+                    #[link_name = concat!("moveref$cxx$vector$", stringify!($ty), "$move_new")]
+                    fn __move_new(this: *mut c_void, src: *mut c_void);
+                }
+                __move_new(this, src);
+            }
+
+            #[inline]
+            unsafe fn __drop(this: *mut c_void) {
+                extern "C" {
+                    #[link_name = concat!("moveref$cxx$vector$", stringify!($ty), "$drop")]
+                    fn __drop(this: *mut c_void);
+                }
+                __drop(this);
+            }
+        }
+    )*};
+}
+
+inline_cxx_vector_element! {
+    u8, u16, u32, u64, usize,
+    i8, i16, i32, i64, isize,
+    f32, f64,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn cxx_string() {
+        bind!(s = new::cxx_string("hello"));
+        assert_eq!(b"hello", s.as_bytes());
+    }
+
+    #[test]
+    fn cxx_string_push() {
+        bind!(mut s = new::cxx_string("hello"));
+        s.as_mut().pin_mut().push_str(", world");
+        assert_eq!(b"hello, world", s.as_bytes());
+    }
+
+    #[test]
+    fn cxx_string_move() {
+        bind!(s = new::cxx_string("a string too long for the small string optimization"));
+        let ptr = s.as_bytes().as_ptr();
+        bind!(t = new::mov(s));
+        assert_eq!(ptr, t.as_bytes().as_ptr());
+        let u = <Box<_> as Emplace<_>>::emplace(new::mov(t));
+        assert_eq!(
+            b"a string too long for the small string optimization",
+            u.as_bytes()
+        );
+    }
+
+    #[test]
+    fn cxx_string_copy() {
+        bind!(s = new::cxx_string("hello"));
+        bind!(mut t = new::copy(&*s));
+        t.as_mut().pin_mut().push_str("!");
+        assert_eq!(b"hello", s.as_bytes());
+        assert_eq!(b"hello!", t.as_bytes());
+    }
+
+    #[test]
+    fn cxx_vector() {
+        bind!(mut v = new::cxx_vector(&[1u32, 2, 3]));
+        v.as_mut().pin_mut().push(4);
+        assert_eq!(&[1, 2, 3, 4], v.as_slice());
+        bind!(w = new::mov(v));
+        assert_eq!(&[1, 2, 3, 4], w.as_slice());
+    }
+
+    #[test]
+    fn cxx_vector_copy() {
+        bind!(v = new::cxx_vector(&[1.0f64, 2.0]));
+        let w = <Box<_> as Emplace<_>>::emplace(new::copy(&*v));
+        assert_eq!(v.as_slice(), w.as_slice());
+    }
+}
@@ -0,0 +1,96 @@
+use core::{mem::MaybeUninit, pin::Pin};
+
+use serde::de::{Deserialize, DeserializeSeed, Deserializer};
+
+/// A [`DeserializeSeed`] which deserializes a value and then moves it into some provided pinned,
+/// uninitialized storage.
+///
+/// Once deserialization succeeds, the storage is in a valid, initialized state. Since the value is
+/// built before it is moved into the storage, large values should instead use
+/// [`new::deserialize_in_place()`](crate::new::deserialize_in_place). This is the
+/// building block for [`new::deserialize()`](crate::new::deserialize), which lets
+/// [`Slot::try_emplace`](crate::Slot::try_emplace) and
+/// [`Emplace::try_emplace`](crate::Emplace::try_emplace) deserialize into their storage.
+#[allow(clippy::module_name_repetitions)]
+pub struct DeserializeNew<'a, T> {
+    /// The storage to deserialize into.
+    this: Pin<&'a mut MaybeUninit<T>>,
+}
+
+impl<'a, T> DeserializeNew<'a, T> {
+    /// Construct a new [`DeserializeNew`] which will deserialize into `this`.
+    #[must_use]
+    #[inline]
+    pub fn new(this: Pin<&'a mut MaybeUninit<T>>) -> Self {
+        return Self { this };
+    }
+}
+
+impl<'de, 'a, T> DeserializeSeed<'de> for DeserializeNew<'a, T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = ();
+
+    #[inline]
+    fn deserialize<D>(mut self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let val = T::deserialize(deserializer)?;
+        self.this.set(MaybeUninit::new(val));
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::de::{
+        value::{Error, SeqDeserializer, U32Deserializer},
+        IntoDeserializer,
+    };
+
+    use crate::*;
+
+    #[test]
+    fn slot_try_emplace() {
+        let deserializer: U32Deserializer<Error> = 42u32.into_deserializer();
+        bind_slot!(slot);
+        let val = slot.try_emplace(new::deserialize(deserializer));
+        assert_eq!(42, *val.unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn box_try_emplace() {
+        let deserializer = SeqDeserializer::<_, Error>::new([1u32, 2, 3].into_iter());
+        let val = <Box<[u32; 3]> as Emplace<_>>::try_emplace(new::deserialize(deserializer));
+        assert_eq!([1, 2, 3], *val.unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn box_try_emplace_in_place() {
+        let deserializer = SeqDeserializer::<_, Error>::new([1u32, 2, 3].into_iter());
+        let new = new::deserialize_in_place(deserializer);
+        let val = <Box<[u32; 3]> as Emplace<_>>::try_emplace(new);
+        assert_eq!([1, 2, 3], *val.unwrap());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn box_try_emplace_in_place_error() {
+        let deserializer = SeqDeserializer::<_, Error>::new([1u32, 2].into_iter());
+        let new = new::deserialize_in_place(deserializer);
+        let val = <Box<[u32; 3]> as Emplace<_>>::try_emplace(new);
+        assert!(val.is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn box_try_emplace_error() {
+        let deserializer = SeqDeserializer::<_, Error>::new([1u32, 2].into_iter());
+        let val = <Box<[u32; 3]> as Emplace<_>>::try_emplace(new::deserialize(deserializer));
+        assert!(val.is_err());
+    }
+}
@@ -1,7 +1,36 @@
-use core::{mem::MaybeUninit, ops::Deref, pin::Pin};
+use core::{alloc::Layout, mem::MaybeUninit, ops::Deref, pin::Pin};
 
 use crate::new::{New, TryNew};
 
+/// Error for fallible emplacement which may fail either while allocating or while constructing.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EmplaceError<E> {
+    /// Allocating storage with the given [`Layout`] failed.
+    Alloc(Layout),
+    /// The initializer failed with an error.
+    New(E),
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for EmplaceError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            | Self::Alloc(layout) => return write!(f, "failed to allocate storage for {layout:?}"),
+            | Self::New(err) => return core::fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: std::error::Error + 'static> std::error::Error for EmplaceError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            | Self::Alloc(_) => return None,
+            | Self::New(err) => return Some(err),
+        }
+    }
+}
+
 /// Operations for constructing [`New`] values into a `Self::Output` instance.
 pub trait Emplace<T>: Sized + Deref {
     type Output: Deref<Target = Self::Target>;
@@ -21,6 +50,245 @@ pub trait Emplace<T>: Sized + Deref {
     ///
     /// Should return `Err` if the `new` initializer fails with an error.
     fn try_emplace<N: TryNew<Output = T>>(new: N) -> Result<Self::Output, N::Error>;
+
+    /// Construct a [`New`] value into a fresh `Self::Output` instance, running `f` on the pinned
+    /// value before it is returned.
+    ///
+    /// This gives `f` access to the final address of the value before any other code can observe
+    /// it, e.g., to register the value with some manager.
+    #[inline]
+    fn emplace_with<N, F>(new: N, f: F) -> Self::Output
+    where
+        N: New<Output = T>,
+        F: FnOnce(Pin<&mut T>),
+    {
+        match Self::try_emplace_with(new, f) {
+            | Ok(val) => return val,
+            | Err(err) => match err {},
+        }
+    }
+
+    /// Try to construct a [`New`] value into a fresh `Self::Output` instance, running `f` on the
+    /// pinned value before it is returned.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Err` if the `new` initializer fails with an error, in which case `f` is not
+    /// run.
+    #[inline]
+    fn try_emplace_with<N, F>(new: N, f: F) -> Result<Self::Output, N::Error>
+    where
+        N: TryNew<Output = T>,
+        F: FnOnce(Pin<&mut T>),
+    {
+        return Self::try_emplace(crate::new::with(new, f));
+    }
+}
+
+/// Operations for constructing [`New`] values into a fresh `Self::Output` instance whose allocation
+/// may fail without aborting.
+#[allow(clippy::module_name_repetitions)]
+pub trait TryAllocEmplace<T>: Emplace<T> {
+    /// Try to construct a [`New`] value into a fresh `Self::Output` instance, reporting allocation
+    /// failure as an error rather than aborting.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Err` if allocating storage fails or if the `new` initializer fails with an
+    /// error.
+    fn try_alloc_emplace<N: TryNew<Output = T>>(
+        new: N,
+    ) -> Result<Self::Output, EmplaceError<N::Error>>;
+}
+
+/// Operations for constructing a slice of [`New`] values into a fresh `Self::Output` instance.
+#[allow(clippy::module_name_repetitions)]
+pub trait EmplaceSlice<T>: Sized + Deref<Target = [T]> {
+    type Output: Deref<Target = Self::Target>;
+
+    /// Construct a slice of `len` elements into a fresh `Self::Output` instance, constructing each
+    /// element in place with the initializer `f(i)` for its index `i`.
+    ///
+    /// If some initializer panics, the already-constructed elements are destroyed in reverse order.
+    #[inline]
+    fn emplace_slice<C, F>(len: usize, f: F) -> Self::Output
+    where
+        C: New<Output = T>,
+        F: FnMut(usize) -> C,
+    {
+        match Self::try_emplace_slice(len, f) {
+            | Ok(val) => return val,
+            | Err(err) => match err {},
+        }
+    }
+
+    /// Try to construct a slice of `len` elements into a fresh `Self::Output` instance, trying to
+    /// construct each element in place with the initializer `f(i)` for its index `i`.
+    ///
+    /// If some initializer fails (or panics), the already-constructed elements are destroyed in
+    /// reverse order.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Err` if some initializer fails with an error.
+    fn try_emplace_slice<C, F>(len: usize, f: F) -> Result<Self::Output, C::Error>
+    where
+        C: TryNew<Output = T>,
+        F: FnMut(usize) -> C;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> EmplaceSlice<T> for crate::Box<[T]> {
+    type Output = Pin<Self>;
+
+    #[inline]
+    fn try_emplace_slice<C, F>(len: usize, f: F) -> Result<Self::Output, C::Error>
+    where
+        C: TryNew<Output = T>,
+        F: FnMut(usize) -> C,
+    {
+        let mut uninit = (0 .. len)
+            .map(|_| return MaybeUninit::<T>::uninit())
+            .collect::<crate::Box<[_]>>();
+        let pin = unsafe { Pin::new_unchecked(&mut *uninit) };
+        unsafe { crate::new::try_init_slice(pin, f)? };
+        let ptr = crate::Box::into_raw(uninit).cast::<T>();
+        let ptr = unsafe { Self::from_raw(core::ptr::slice_from_raw_parts_mut(ptr, len)) };
+        return Ok(Self::into_pin(ptr));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> EmplaceSlice<T> for crate::Rc<[T]> {
+    type Output = Pin<Self>;
+
+    #[inline]
+    fn try_emplace_slice<C, F>(len: usize, f: F) -> Result<Self::Output, C::Error>
+    where
+        C: TryNew<Output = T>,
+        F: FnMut(usize) -> C,
+    {
+        let mut uninit = (0 .. len)
+            .map(|_| return MaybeUninit::<T>::uninit())
+            .collect::<crate::Rc<[_]>>();
+        let ptr = crate::Rc::get_mut(&mut uninit).expect("unreachable: freshly allocated");
+        let pin = unsafe { Pin::new_unchecked(ptr) };
+        unsafe { crate::new::try_init_slice(pin, f)? };
+        let ptr = crate::Rc::into_raw(uninit).cast::<T>();
+        let ptr = unsafe { Self::from_raw(core::ptr::slice_from_raw_parts(ptr, len)) };
+        let pin = unsafe { Pin::new_unchecked(ptr) };
+        return Ok(pin);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> EmplaceSlice<T> for crate::Arc<[T]> {
+    type Output = Pin<Self>;
+
+    #[inline]
+    fn try_emplace_slice<C, F>(len: usize, f: F) -> Result<Self::Output, C::Error>
+    where
+        C: TryNew<Output = T>,
+        F: FnMut(usize) -> C,
+    {
+        let mut uninit = (0 .. len)
+            .map(|_| return MaybeUninit::<T>::uninit())
+            .collect::<crate::Arc<[_]>>();
+        let ptr = crate::Arc::get_mut(&mut uninit).expect("unreachable: freshly allocated");
+        let pin = unsafe { Pin::new_unchecked(ptr) };
+        unsafe { crate::new::try_init_slice(pin, f)? };
+        let ptr = crate::Arc::into_raw(uninit).cast::<T>();
+        let ptr = unsafe { Self::from_raw(core::ptr::slice_from_raw_parts(ptr, len)) };
+        let pin = unsafe { Pin::new_unchecked(ptr) };
+        return Ok(pin);
+    }
+}
+
+/// Operations for constructing [`New`] values into the storage of an existing pinned pointer, in
+/// place of its current value.
+#[allow(clippy::module_name_repetitions)]
+pub trait ReEmplace<T>: Sized {
+    /// Destroy the current value in place and construct `new` into the same storage.
+    ///
+    /// # Panics
+    ///
+    /// If `new` panics, the process is aborted rather than unwinding, since `self` would otherwise
+    /// be left without a valid value.
+    fn re_emplace<N: New<Output = T>>(&mut self, new: N);
+
+    /// Destroy the current value in place and try to construct `new` into the same storage.
+    ///
+    /// If `new` fails (or panics), the storage is deallocated without being destroyed again.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Err` if the `new` initializer fails with an error.
+    fn try_re_emplace<N: TryNew<Output = T>>(self, new: N) -> Result<Self, N::Error>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ReEmplace<T> for Pin<crate::Box<T>> {
+    #[inline]
+    fn re_emplace<N: New<Output = T>>(&mut self, new: N) {
+        let guard = crate::swap::AbortOnUnwind;
+        unsafe {
+            let ptr = core::ptr::from_mut(self.as_mut().get_unchecked_mut());
+            core::ptr::drop_in_place(ptr);
+            new.new(Pin::new_unchecked(&mut *ptr.cast::<MaybeUninit<T>>()));
+        }
+        core::mem::forget(guard);
+    }
+
+    #[inline]
+    fn try_re_emplace<N: TryNew<Output = T>>(self, new: N) -> Result<Self, N::Error> {
+        let ptr = crate::Box::into_raw(unsafe { Self::into_inner_unchecked(self) });
+        let mut uninit = unsafe { crate::Box::from_raw(ptr.cast::<MaybeUninit<T>>()) };
+        unsafe { uninit.assume_init_drop() };
+        let pin = unsafe { Pin::new_unchecked(&mut *uninit) };
+        unsafe { new.try_new(pin)? };
+        let ptr = unsafe { crate::Box::from_raw(crate::Box::into_raw(uninit).cast::<T>()) };
+        return Ok(crate::Box::into_pin(ptr));
+    }
+}
+
+/// Operations for constructing [`New`] values into an optional pinned pointer if it is empty.
+#[allow(clippy::module_name_repetitions)]
+pub trait GetOrEmplace<T> {
+    /// Return the pinned value, constructing it into fresh storage with `new` if there is none.
+    #[inline]
+    fn get_or_emplace<N: New<Output = T>>(&mut self, new: N) -> Pin<&mut T> {
+        match self.get_or_try_emplace(new) {
+            | Ok(pin) => return pin,
+            | Err(err) => match err {},
+        }
+    }
+
+    /// Return the pinned value, trying to construct it into fresh storage with `new` if there is
+    /// none.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Err` if the `new` initializer fails with an error, in which case `self` is
+    /// left empty.
+    fn get_or_try_emplace<N: TryNew<Output = T>>(
+        &mut self,
+        new: N,
+    ) -> Result<Pin<&mut T>, N::Error>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> GetOrEmplace<T> for Option<Pin<crate::Box<T>>> {
+    #[inline]
+    fn get_or_try_emplace<N: TryNew<Output = T>>(
+        &mut self,
+        new: N,
+    ) -> Result<Pin<&mut T>, N::Error> {
+        if self.is_none() {
+            *self = Some(crate::Box::try_emplace(new)?);
+        }
+        let pin = self.as_mut().expect("unreachable: freshly emplaced");
+        return Ok(pin.as_mut());
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -29,14 +297,39 @@ impl<T> Emplace<T> for crate::Box<T> {
 
     #[inline]
     fn try_emplace<N: TryNew<Output = T>>(new: N) -> Result<Self::Output, N::Error> {
-        let mut uninit = crate::Box::new(MaybeUninit::<T>::uninit());
+        match <Self as TryAllocEmplace<T>>::try_alloc_emplace(new) {
+            | Ok(pin) => return Ok(pin),
+            | Err(EmplaceError::Alloc(layout)) => alloc::alloc::handle_alloc_error(layout),
+            | Err(EmplaceError::New(err)) => return Err(err),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> TryAllocEmplace<T> for crate::Box<T> {
+    #[inline]
+    fn try_alloc_emplace<N: TryNew<Output = T>>(
+        new: N,
+    ) -> Result<Self::Output, EmplaceError<N::Error>> {
+        let layout = Layout::new::<T>();
+        let ptr = if layout.size() == 0 {
+            core::ptr::NonNull::<MaybeUninit<T>>::dangling()
+        } else {
+            let raw = unsafe { alloc::alloc::alloc(layout) }.cast::<MaybeUninit<T>>();
+            core::ptr::NonNull::new(raw).ok_or(EmplaceError::Alloc(layout))?
+        };
+        let mut uninit = unsafe { crate::Box::from_raw(ptr.as_ptr()) };
         let pin = unsafe { Pin::new_unchecked(&mut *uninit) };
-        unsafe { new.try_new(pin)? };
+        unsafe { new.try_new(pin).map_err(EmplaceError::New)? };
         let ptr = unsafe { Self::from_raw(crate::Box::into_raw(uninit).cast::<T>()) };
         return Ok(Self::into_pin(ptr));
     }
 }
 
+/// Emplacement into an [`Rc`](crate::Rc).
+///
+/// Since fallible [`Rc`](crate::Rc) allocation is not yet stable, this aborts if allocation fails
+/// and [`TryAllocEmplace`] is not implemented.
 #[cfg(feature = "alloc")]
 impl<T> Emplace<T> for crate::Rc<T> {
     type Output = Pin<Self>;
@@ -53,6 +346,10 @@ impl<T> Emplace<T> for crate::Rc<T> {
     }
 }
 
+/// Emplacement into an [`Arc`](crate::Arc).
+///
+/// Since fallible [`Arc`](crate::Arc) allocation is not yet stable, this aborts if allocation fails
+/// and [`TryAllocEmplace`] is not implemented.
 #[cfg(feature = "alloc")]
 impl<T> Emplace<T> for crate::Arc<T> {
     type Output = Pin<Self>;
@@ -70,6 +367,77 @@ impl<T> Emplace<T> for crate::Arc<T> {
     }
 }
 
+/// Types whose C++ size and alignment are known on the Rust side, so that storage for them can be
+/// allocated from Rust.
+///
+/// Types declared opaquely within a `cxx::bridge` are zero-sized on the Rust side, so their layout
+/// is typically obtained from a bridged C++ function returning `sizeof(T)` and `alignof(T)`.
+///
+/// # Safety
+///
+/// - [`CxxLayout::layout`] must return the size and alignment of the C++ type
+#[cfg(feature = "cxx")]
+pub unsafe trait CxxLayout {
+    /// Return the layout of the C++ type.
+    fn layout() -> Layout;
+}
+
+/// Emplacement into a [`cxx::UniquePtr`] whose storage is allocated with the C++ `operator new`, so
+/// that C++ code taking ownership of the pointer can `delete` it with the matching allocator.
+///
+/// [`cxx::SharedPtr`] is not supported, since `cxx` offers no way to adopt a raw pointer into one.
+#[cfg(feature = "cxx")]
+impl<T> Emplace<T> for cxx::UniquePtr<T>
+where
+    T: cxx::memory::UniquePtrTarget + cxx::ExternType<Kind = cxx::kind::Opaque> + CxxLayout,
+{
+    type Output = Self;
+
+    #[inline]
+    fn try_emplace<N: TryNew<Output = T>>(new: N) -> Result<Self::Output, N::Error> {
+        match <Self as TryAllocEmplace<T>>::try_alloc_emplace(new) {
+            | Ok(ptr) => return Ok(ptr),
+            | Err(EmplaceError::Alloc(layout)) => alloc::alloc::handle_alloc_error(layout),
+            | Err(EmplaceError::New(err)) => return Err(err),
+        }
+    }
+}
+
+#[cfg(feature = "cxx")]
+impl<T> TryAllocEmplace<T> for cxx::UniquePtr<T>
+where
+    T: cxx::memory::UniquePtrTarget + cxx::ExternType<Kind = cxx::kind::Opaque> + CxxLayout,
+{
+    #[inline]
+    fn try_alloc_emplace<N: TryNew<Output = T>>(
+        new: N,
+    ) -> Result<Self::Output, EmplaceError<N::Error>> {
+        /// Helper type for deallocating the storage if construction fails or unwinds.
+        struct Dealloc {
+            /// The storage to deallocate.
+            ptr: core::ptr::NonNull<u8>,
+            /// The layout the storage was allocated with.
+            layout: Layout,
+        }
+
+        impl Drop for Dealloc {
+            #[inline]
+            fn drop(&mut self) {
+                unsafe { crate::cxx_ffi::operator_delete(self.ptr, self.layout) };
+            }
+        }
+
+        let layout = T::layout();
+        let ptr = crate::cxx_ffi::operator_new(layout).ok_or(EmplaceError::Alloc(layout))?;
+        let dealloc = Dealloc { ptr, layout };
+        let uninit = unsafe { &mut *ptr.as_ptr().cast::<MaybeUninit<T>>() };
+        let pin = unsafe { Pin::new_unchecked(uninit) };
+        unsafe { new.try_new(pin).map_err(EmplaceError::New)? };
+        core::mem::forget(dealloc);
+        return Ok(unsafe { Self::from_raw(ptr.as_ptr().cast::<T>()) });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     mod coverage {
@@ -92,6 +460,265 @@ mod tests {
                 assert_eq!(VAL, *out);
             }
 
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn box_try_alloc_emplace() {
+                const VAL: [u64; 4] = [1, 2, 3, 4];
+                let new = crate::new::of(VAL);
+                let out =
+                    <crate::Box<_> as crate::TryAllocEmplace<_>>::try_alloc_emplace(new).unwrap();
+                assert_eq!(VAL, *out);
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn box_try_alloc_emplace_zero_sized() {
+                const VAL: [u8; 0] = [];
+                let new = crate::new::of(VAL);
+                let out =
+                    <crate::Box<_> as crate::TryAllocEmplace<_>>::try_alloc_emplace(new).unwrap();
+                assert_eq!(VAL, *out);
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn box_try_alloc_emplace_error() {
+                use crate::fixtures::Fail;
+
+                let out = <crate::Box<_> as crate::TryAllocEmplace<_>>::try_alloc_emplace(
+                    Fail::<u8>::new(),
+                );
+                assert_eq!(Err(crate::EmplaceError::New("failed")), out.map(|_| ()));
+            }
+
+            #[cfg(feature = "std")]
+            #[test]
+            fn emplace_error_source() {
+                use std::{error::Error, fmt, string::ToString};
+
+                #[derive(Debug)]
+                struct Inner;
+                impl fmt::Display for Inner {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        return f.write_str("inner");
+                    }
+                }
+                impl Error for Inner {
+                }
+
+                let err = crate::EmplaceError::New(Inner);
+                assert_eq!("inner", err.to_string());
+                assert!(err.source().is_some());
+                let err = crate::EmplaceError::<Inner>::Alloc(core::alloc::Layout::new::<u8>());
+                assert!(err.source().is_none());
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn box_emplace_with() {
+                let mut addr = core::ptr::null();
+                let new = crate::new::of(0u8);
+                let out = <crate::Box<_> as crate::Emplace<_>>::emplace_with(new, |mut pin| {
+                    *pin = 1;
+                    addr = core::ptr::from_ref(&*pin);
+                });
+                assert_eq!(1, *out);
+                assert_eq!(addr, core::ptr::from_ref(&*out));
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn rc_emplace_with() {
+                let mut addr = core::ptr::null();
+                let new = crate::new::of(0u8);
+                let out = <crate::Rc<_> as crate::Emplace<_>>::emplace_with(new, |pin| {
+                    addr = core::ptr::from_ref(&*pin);
+                });
+                assert_eq!(addr, core::ptr::from_ref(&*out));
+            }
+
+            #[cfg(feature = "std")]
+            #[test]
+            fn box_emplace_with_panic() {
+                use core::sync::atomic::{AtomicUsize, Ordering};
+
+                use crate::fixtures::Counted;
+
+                static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+                let result = std::panic::catch_unwind(|| {
+                    let new = crate::new::of(Counted::new(&DROPS));
+                    return <crate::Box<_> as crate::Emplace<_>>::emplace_with(new, |_| panic!());
+                });
+                assert!(result.is_err());
+                assert_eq!(1, DROPS.load(Ordering::SeqCst));
+            }
+
+            #[cfg(feature = "cxx")]
+            mod unique_ptr {
+                use core::{
+                    mem::MaybeUninit,
+                    pin::Pin,
+                    sync::atomic::{AtomicUsize, Ordering},
+                };
+
+                use cxx::UniquePtr;
+                use moveref_cxx_test::ffi;
+
+                use crate::{Emplace, TryAllocEmplace};
+
+                unsafe impl crate::CxxLayout for ffi::Widget {
+                    fn layout() -> core::alloc::Layout {
+                        let (size, align) = (ffi::widget_size(), ffi::widget_align());
+                        return core::alloc::Layout::from_size_align(size, align).unwrap();
+                    }
+                }
+
+                fn widget(
+                    val: u64,
+                    drops: &'static AtomicUsize,
+                ) -> impl crate::New<Output = ffi::Widget> {
+                    unsafe {
+                        return crate::new::by_raw(
+                            move |this: Pin<&mut MaybeUninit<ffi::Widget>>| {
+                                let this = Pin::into_inner_unchecked(this).as_mut_ptr();
+                                ffi::widget_new(this, val, drops.as_ptr());
+                            },
+                        );
+                    }
+                }
+
+                #[test]
+                fn layout() {
+                    use crate::CxxLayout;
+
+                    assert_eq!(0, core::mem::size_of::<ffi::Widget>());
+                    assert_eq!(32, ffi::Widget::layout().size());
+                }
+
+                #[test]
+                fn emplace() {
+                    static DROPS: AtomicUsize = AtomicUsize::new(0);
+                    let out = UniquePtr::emplace(widget(128, &DROPS));
+                    assert_eq!(128, out.value());
+                    assert_eq!(0, DROPS.load(Ordering::SeqCst));
+                    drop(out);
+                    assert_eq!(1, DROPS.load(Ordering::SeqCst));
+                }
+
+                #[test]
+                fn emplace_with() {
+                    static DROPS: AtomicUsize = AtomicUsize::new(0);
+                    let mut addr = core::ptr::null();
+                    let out = UniquePtr::emplace_with(widget(0, &DROPS), |pin| {
+                        addr = core::ptr::from_ref(&*pin);
+                    });
+                    assert_eq!(addr, core::ptr::from_ref(&*out));
+                }
+
+                #[test]
+                fn try_alloc_emplace_error() {
+                    struct Fail;
+                    impl crate::new::TryNew for Fail {
+                        type Output = ffi::Widget;
+                        type Error = &'static str;
+                        unsafe fn try_new(
+                            self,
+                            _this: Pin<&mut MaybeUninit<Self::Output>>,
+                        ) -> Result<(), Self::Error> {
+                            return Err("failed");
+                        }
+                    }
+
+                    let out = UniquePtr::try_alloc_emplace(Fail);
+                    assert_eq!(Err(crate::EmplaceError::New("failed")), out.map(|_| ()));
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn box_re_emplace() {
+                use core::sync::atomic::{AtomicUsize, Ordering};
+
+                use crate::{fixtures::Counted, ReEmplace};
+
+                static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+                let new = crate::new::of(Counted::with(&DROPS, 1));
+                let mut out = <crate::Box<_> as crate::Emplace<_>>::emplace(new);
+                let addr = core::ptr::from_ref(&*out);
+                out.re_emplace(crate::new::of(Counted::with(&DROPS, 2)));
+                assert_eq!(2, out.val);
+                assert_eq!(addr, core::ptr::from_ref(&*out));
+                assert_eq!(1, DROPS.load(Ordering::SeqCst));
+                let new = crate::new::of(Counted::with(&DROPS, 3));
+                let out = out.try_re_emplace(new).unwrap();
+                assert_eq!(3, out.val);
+                assert_eq!(addr, core::ptr::from_ref(&*out));
+                assert_eq!(2, DROPS.load(Ordering::SeqCst));
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn box_try_re_emplace_error() {
+                use crate::{fixtures::Fail, ReEmplace};
+
+                let out = <crate::Box<_> as crate::Emplace<_>>::emplace(crate::new::of(1u8));
+                assert_eq!(Err("failed"), out.try_re_emplace(Fail::new()).map(|_| ()));
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn option_box_get_or_emplace() {
+                use crate::GetOrEmplace;
+
+                let mut out = None::<core::pin::Pin<crate::Box<u8>>>;
+                assert_eq!(1, *out.get_or_emplace(crate::new::of(1)));
+                assert_eq!(1, *out.get_or_emplace(crate::new::of(2)));
+                assert_eq!(Some(1), out.as_deref().copied());
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn slice() {
+                use crate::EmplaceSlice;
+
+                let con = |i| return crate::new::of(i * 2);
+                let out = <crate::Box<[_]> as EmplaceSlice<_>>::emplace_slice(3, con);
+                assert_eq!([0, 2, 4], *out);
+                let out = <crate::Rc<[_]> as EmplaceSlice<_>>::emplace_slice(3, con);
+                assert_eq!([0, 2, 4], *out);
+                let out = <crate::Arc<[_]> as EmplaceSlice<_>>::emplace_slice(0, con);
+                assert!(out.is_empty());
+            }
+
+            #[cfg(feature = "alloc")]
+            #[test]
+            fn slice_error() {
+                use core::{
+                    mem::MaybeUninit,
+                    pin::Pin,
+                    sync::atomic::{AtomicUsize, Ordering},
+                };
+
+                use crate::{fixtures::Counted, EmplaceSlice};
+
+                static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+                let con = |i| unsafe {
+                    return crate::new::try_by_raw(move |this: Pin<&mut MaybeUninit<Counted>>| {
+                        if i == 2 {
+                            return Err(i);
+                        }
+                        Pin::into_inner_unchecked(this).write(Counted::new(&DROPS));
+                        return Ok(());
+                    });
+                };
+                let out = <crate::Box<[_]> as EmplaceSlice<_>>::try_emplace_slice(4, con);
+                assert_eq!(Err(2), out.map(|_| ()));
+                assert_eq!(2, DROPS.load(Ordering::SeqCst));
+            }
+
             #[cfg(feature = "alloc")]
             #[test]
             fn rc() {
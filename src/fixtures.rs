@@ -0,0 +1,57 @@
+use core::{
+    marker::PhantomData,
+    mem::MaybeUninit,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::new::TryNew;
+
+/// A [`TryNew`] value for any `T` which always fails with the error `"failed"`.
+pub(crate) struct Fail<T>(PhantomData<fn() -> T>);
+
+impl<T> Fail<T> {
+    /// Construct a new [`Fail`].
+    pub(crate) const fn new() -> Self {
+        return Self(PhantomData);
+    }
+}
+
+impl<T> TryNew for Fail<T> {
+    type Output = T;
+    type Error = &'static str;
+
+    unsafe fn try_new(self, _this: Pin<&mut MaybeUninit<Self::Output>>) -> Result<(), Self::Error> {
+        return Err("failed");
+    }
+}
+
+/// A value which counts its destructions in `drops`.
+pub(crate) struct Counted<'a> {
+    /// The number of destructions, usually a per-test `static` since tests run concurrently.
+    drops: &'a AtomicUsize,
+    /// A value carried along for checking which instance is live.
+    pub(crate) val: u8,
+}
+
+impl<'a> Counted<'a> {
+    /// Construct a new [`Counted`] carrying `0`.
+    pub(crate) const fn new(drops: &'a AtomicUsize) -> Self {
+        return Self::with(drops, 0);
+    }
+
+    /// Construct a new [`Counted`] carrying `val`.
+    pub(crate) const fn with(drops: &'a AtomicUsize, val: u8) -> Self {
+        return Self { drops, val };
+    }
+}
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        self.drops.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+trivial_move! {
+    Counted<'a> where ['a],
+}
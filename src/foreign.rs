@@ -0,0 +1,145 @@
+use core::{
+    marker::{PhantomData, PhantomPinned},
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::Deref,
+    pin::Pin,
+};
+
+use crate::new::New;
+
+/// Foreign types which are destroyed in place by a foreign destructor function, such as
+/// `void foo_destroy(foo_t *)` in C.
+///
+/// # Safety
+///
+/// Implementations must ensure that [`ForeignDrop::foreign_drop`] destroys a value which was
+/// initialized by the corresponding foreign constructor, such that it is not used afterward.
+#[allow(clippy::module_name_repetitions)]
+pub unsafe trait ForeignDrop {
+    /// Destroy the value at `this` in place.
+    ///
+    /// # Safety
+    ///
+    /// - `this` must point to a value initialized by its foreign constructor
+    /// - the value must not already have been destroyed
+    unsafe fn foreign_drop(this: *mut Self);
+}
+
+/// A foreign value which was constructed in place by a foreign constructor function, and which is
+/// destroyed in place by its [`ForeignDrop`] destructor.
+///
+/// Since foreign code may retain the address of the value, it is always pinned once constructed.
+#[repr(transparent)]
+pub struct Foreign<T: ForeignDrop> {
+    /// The foreign value, which is destroyed by [`ForeignDrop`] rather than by Rust.
+    value: ManuallyDrop<T>,
+    /// Marker making the value `!Unpin`, since foreign code may retain its address.
+    _pinned: PhantomPinned,
+}
+
+impl<T: ForeignDrop> Foreign<T> {
+    /// Return a raw pointer to the foreign value, for passing to foreign functions which mutate it.
+    #[must_use]
+    #[inline]
+    pub fn as_mut_ptr(self: Pin<&mut Self>) -> *mut T {
+        let this = unsafe { self.get_unchecked_mut() };
+        return core::ptr::from_mut(&mut *this.value);
+    }
+}
+
+impl<T: ForeignDrop> Deref for Foreign<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        return &self.value;
+    }
+}
+
+impl<T: ForeignDrop> Drop for Foreign<T> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { T::foreign_drop(&mut *self.value) };
+    }
+}
+
+/// A [`New`] value which constructs a [`Foreign<T>`] in place by calling a foreign constructor
+/// function, such as `void foo_init(foo_t *out, ...)` in C.
+///
+/// This is created by [`new::from_c`](crate::new::from_c).
+#[allow(clippy::module_name_repetitions)]
+pub struct CNew<T, F> {
+    /// The thunk which calls the foreign constructor.
+    init: F,
+    /// Phantom type holding `T`, respecting variance.
+    _type: PhantomData<fn(*mut T)>,
+}
+
+impl<T, F> CNew<T, F> {
+    /// Construct a new [`CNew`] from the thunk `init`.
+    ///
+    /// # Safety
+    ///
+    /// - `init` must fully initialize the value at the pointer it is given
+    #[inline]
+    pub(crate) const unsafe fn new_unchecked(init: F) -> Self {
+        return Self {
+            init,
+            _type: PhantomData, // tarpaulin
+        };
+    }
+}
+
+impl<T, F> New for CNew<T, F>
+where
+    T: ForeignDrop,
+    F: FnOnce(*mut T),
+{
+    type Output = Foreign<T>;
+
+    #[inline]
+    unsafe fn new(self, this: Pin<&mut MaybeUninit<Self::Output>>) {
+        let this = Pin::into_inner_unchecked(this).as_mut_ptr();
+        (self.init)(this.cast::<T>());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::*;
+
+    static DESTROYED: AtomicUsize = AtomicUsize::new(0);
+
+    #[repr(C)]
+    struct FooT {
+        val: u32,
+    }
+
+    unsafe extern "C" fn foo_init(out: *mut FooT, val: u32) {
+        out.write(FooT { val });
+    }
+
+    unsafe extern "C" fn foo_destroy(this: *mut FooT) {
+        (*this).val = 0;
+        DESTROYED.fetch_add(1, Ordering::SeqCst);
+    }
+
+    unsafe impl ForeignDrop for FooT {
+        unsafe fn foreign_drop(this: *mut Self) {
+            foo_destroy(this);
+        }
+    }
+
+    #[test]
+    fn from_c() {
+        {
+            bind!(mut foo = unsafe { new::from_c(|out| foo_init(out, 42)) });
+            assert_eq!(42, foo.val);
+            let ptr = foo.as_mut().as_mut_ptr();
+            assert_eq!(core::ptr::from_ref(&**foo), ptr.cast_const());
+        }
+        assert_eq!(1, DESTROYED.load(Ordering::SeqCst));
+    }
+}
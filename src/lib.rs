@@ -13,6 +13,10 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "macros")]
+extern crate self as moveref;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "alloc")]
 pub(crate) use alloc::{boxed::Box, rc::Rc, sync::Arc};
@@ -21,28 +25,69 @@ pub(crate) use alloc::{boxed::Box, rc::Rc, sync::Arc};
 #[macro_use]
 mod macros;
 
+/// Foreign functions implemented by the C++ support library.
+#[cfg(feature = "cxx")]
+mod cxx_ffi;
+/// Inline storage for C++ standard library types.
+#[cfg(feature = "cxx")]
+mod cxx_inline;
 /// Dereferencing move operations.
 mod deref_move;
+/// Deserialization directly into storage.
+#[cfg(feature = "serde")]
+mod deserialize;
 /// Emplacement operations for constructing values.
 mod emplace;
+/// Test fixtures shared between modules.
+#[cfg(test)]
+mod fixtures;
+/// Adapters for foreign constructors and destructors.
+mod foreign;
 /// Movement operations.
 mod into_move;
 /// Move-dereferencing uniquely-owning references.
 mod move_ref;
 /// Construction operations.
 pub mod new;
+/// Shared state for values constructed at most once.
+mod once_state;
+/// Lazily-constructed cells for pinned values.
+#[cfg(feature = "std")]
+mod pin_once_cell;
+/// Self-referential values which are fixed up after construction and movement.
+mod self_ref;
 /// Storage slots for move-references.
 mod slot;
 /// Storage slot implementation details.
 mod slot_storage;
-
+/// Storage slots for statically-placed values.
+mod static_slot;
+/// Swapping and replacement of pinned values.
+mod swap;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+#[cfg(feature = "cxx")]
+pub use cxx_inline::{InlineCxxString, InlineCxxVector, InlineCxxVectorElement};
 pub use deref_move::DerefMove;
-pub use emplace::Emplace;
+#[cfg(feature = "serde")]
+pub use deserialize::DeserializeNew;
+#[cfg(feature = "cxx")]
+pub use emplace::CxxLayout;
+pub use emplace::{Emplace, EmplaceError, EmplaceSlice, GetOrEmplace, ReEmplace, TryAllocEmplace};
+pub use foreign::{CNew, Foreign, ForeignDrop};
 pub use into_move::IntoMove;
 pub use move_ref::MoveRef;
+#[cfg(feature = "macros")]
+pub use moveref_macros::ctor;
 pub use new::{CopyNew, MoveNew, New};
+#[cfg(feature = "std")]
+pub use pin_once_cell::PinOnceCell;
+pub use self_ref::SelfRef;
 pub use slot::Slot;
-pub use slot_storage::{SlotStorage, SlotStorageKind};
+pub use slot_storage::{SlotStorage, SlotStorageKind, SlotStorageState};
+pub use static_slot::StaticSlot;
+pub use swap::{replace, swap};
 
 trivial_copy! {
     (),
@@ -178,3 +223,33 @@ trivial_move! {
 
     ::core::time::Duration,
 }
+
+#[cfg(feature = "alloc")]
+trivial_copy! {
+    ::alloc::boxed::Box<T> where [T: ?Sized],
+    ::alloc::collections::BTreeMap<K, V> where [K, V],
+    ::alloc::rc::Rc<T> where [T: ?Sized],
+    ::alloc::string::String,
+    ::alloc::sync::Arc<T> where [T: ?Sized],
+    ::alloc::vec::Vec<T> where [T],
+}
+
+#[cfg(feature = "alloc")]
+trivial_move! {
+    ::alloc::boxed::Box<T> where [T: ?Sized],
+    ::alloc::collections::BTreeMap<K, V> where [K, V],
+    ::alloc::rc::Rc<T> where [T: ?Sized],
+    ::alloc::string::String,
+    ::alloc::sync::Arc<T> where [T: ?Sized],
+    ::alloc::vec::Vec<T> where [T],
+}
+
+#[cfg(feature = "std")]
+trivial_copy! {
+    ::std::collections::HashMap<K, V, S> where [K, V, S],
+}
+
+#[cfg(feature = "std")]
+trivial_move! {
+    ::std::collections::HashMap<K, V, S> where [K, V, S],
+}
@@ -15,29 +15,72 @@
 /// The above invocaton constructs a [`New`](crate::New) value into a fresh
 /// [`MoveRef`](crate::MoveRef) bound to `x`.
 ///
-/// - `bind!(mut x: T = ...)` (with right-hand side of `&move *ptr` or `&move val` or `con`)
+/// - `bind!(x = try con)` creates an `x: Pin<MoveRef<T>>` given `con: impl TryNew<Output = T>`
+///
+/// The above invocation tries to construct a [`TryNew`](crate::new::TryNew) value into a fresh
+/// [`MoveRef`](crate::MoveRef) bound to `x`, returning early from the enclosing function with the
+/// error (converted with `?`) if construction fails.
+///
+/// - `bind!(mut x: T = ...)` (with right-hand side of `&move *ptr` or `&move val` or `con` or
+///   `try con`)
 ///
 /// The above generalization can be used with any earlier invocation form to add mutability and
 /// typing annotations.
+///
+/// - `bind!((a, mut b) = &move *ptr)` or `bind!((a, mut b) = &move val)`
+///
+/// The above invocation destructures a tuple (possibly nested) into a fresh
+/// [`MoveRef`](crate::MoveRef) for each component. A component matched by `_` is dropped
+/// immediately. The constructor form `con` cannot be destructured, since the components of a
+/// pinned tuple must not be moved apart.
+///
+/// - `bind!(a = ..., b = ..., (c, d) = ...)`
+///
+/// The above invocation binds several variables in one invocation, using any of the earlier forms
+/// for each binding.
 #[macro_export]
 macro_rules! bind {
-    (mut $name:ident $(: $ty:ty)? = &move *$expr:expr) => {
-        $crate::bind!(@move(mut) $name, $($ty)?, $expr)
+    () => {};
+    (($($pat:tt)*) = &move *$expr:expr $(, $($rest:tt)*)?) => {
+        $crate::bind!(@move tuple, , $expr);
+        $crate::bind!(@parts ($($pat)*), $crate::MoveRef::into_inner(tuple));
+        $crate::bind!($($($rest)*)?);
     };
-    ($name:ident $(: $ty:ty)? = &move *$expr:expr) => {
-        $crate::bind!(@move $name, $($ty)?, $expr)
+    (($($pat:tt)*) = &move $expr:expr $(, $($rest:tt)*)?) => {
+        $crate::bind!(@parts ($($pat)*), $expr);
+        $crate::bind!($($($rest)*)?);
     };
-    (mut $name:ident $(: $ty:ty)? = &move $expr:expr) => {
-        $crate::bind!(@put(mut) $name, $($ty)?, $expr)
+    (mut $name:ident $(: $ty:ty)? = try $expr:expr $(, $($rest:tt)*)?) => {
+        $crate::bind!(@try_emplace(mut) $name, $($ty)?, $expr);
+        $crate::bind!($($($rest)*)?);
     };
-    ($name:ident $(: $ty:ty)? = &move $expr:expr) => {
-        $crate::bind!(@put $name, $($ty)?, $expr)
+    ($name:ident $(: $ty:ty)? = try $expr:expr $(, $($rest:tt)*)?) => {
+        $crate::bind!(@try_emplace $name, $($ty)?, $expr);
+        $crate::bind!($($($rest)*)?);
     };
-    (mut $name:ident $(: $ty:ty)? = $expr:expr) => {
+    (mut $name:ident $(: $ty:ty)? = &move *$expr:expr $(, $($rest:tt)*)?) => {
+        $crate::bind!(@move(mut) $name, $($ty)?, $expr);
+        $crate::bind!($($($rest)*)?);
+    };
+    ($name:ident $(: $ty:ty)? = &move *$expr:expr $(, $($rest:tt)*)?) => {
+        $crate::bind!(@move $name, $($ty)?, $expr);
+        $crate::bind!($($($rest)*)?);
+    };
+    (mut $name:ident $(: $ty:ty)? = &move $expr:expr $(, $($rest:tt)*)?) => {
+        $crate::bind!(@put(mut) $name, $($ty)?, $expr);
+        $crate::bind!($($($rest)*)?);
+    };
+    ($name:ident $(: $ty:ty)? = &move $expr:expr $(, $($rest:tt)*)?) => {
+        $crate::bind!(@put $name, $($ty)?, $expr);
+        $crate::bind!($($($rest)*)?);
+    };
+    (mut $name:ident $(: $ty:ty)? = $expr:expr $(, $($rest:tt)*)?) => {
         $crate::bind!(@emplace(mut) $name, $($ty)?, $expr);
+        $crate::bind!($($($rest)*)?);
     };
-    ($name:ident $(: $ty:ty)? = $expr:expr) => {
+    ($name:ident $(: $ty:ty)? = $expr:expr $(, $($rest:tt)*)?) => {
         $crate::bind!(@emplace $name, $($ty)?, $expr);
+        $crate::bind!($($($rest)*)?);
     };
     (@move $(($mut:tt))? $name:ident, $($ty:ty)?, $expr:expr) => {
         $crate::bind_slot!(#[dropping] slot);
@@ -52,6 +95,31 @@ macro_rules! bind {
         $crate::bind_slot!(slot);
         let $($mut)? $name $(: $ty)? = slot.emplace($expr);
     };
+    (@try_emplace $(($mut:tt))? $name:ident, $($ty:ty)?, $expr:expr) => {
+        $crate::bind_slot!(slot);
+        let $($mut)? $name $(: $ty)? = slot.try_emplace($expr)?;
+    };
+    (@parts ($($pat:tt)*), $expr:expr) => {
+        #[allow(unused_mut)]
+        let ($($pat)*) = $expr;
+        $crate::bind!(@each $($pat)*);
+    };
+    (@each) => {};
+    (@each ($($pat:tt)*) $(, $($rest:tt)*)?) => {
+        $crate::bind!(@each $($pat)*);
+        $crate::bind!(@each $($($rest)*)?);
+    };
+    (@each _ $(, $($rest:tt)*)?) => {
+        $crate::bind!(@each $($($rest)*)?);
+    };
+    (@each mut $name:ident $(, $($rest:tt)*)?) => {
+        $crate::bind!(@put(mut) $name, , $name);
+        $crate::bind!(@each $($($rest)*)?);
+    };
+    (@each $name:ident $(, $($rest:tt)*)?) => {
+        $crate::bind!(@put $name, , $name);
+        $crate::bind!(@each $($($rest)*)?);
+    };
 }
 
 /// Macro for creating a fresh [`MoveRef`](crate::MoveRef) expression.
@@ -79,8 +147,17 @@ macro_rules! bind {
 ///
 /// The above invocaton constructs a [`New`](crate::New) value into a fresh
 /// [`MoveRef`](crate::MoveRef).
+///
+/// - `expr!(try con)` creates a `Result<Pin<MoveRef<T>>, E>` given `con: impl TryNew<Output = T,
+///   Error = E>`
+///
+/// The above invocation tries to construct a [`TryNew`](crate::new::TryNew) value into a fresh
+/// [`MoveRef`](crate::MoveRef).
 #[macro_export]
 macro_rules! expr {
+    (try $expr:expr) => {
+        $crate::expr_slot!().try_emplace($expr)
+    };
     (&move *$expr:expr) => {
         $crate::DerefMove::deref_move(
             $expr,
@@ -208,6 +285,64 @@ mod test {
             assert_eq!(VAL, *expr!(&move *Box::new(VAL)));
         }
 
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn bind_many() {
+            bind!(a = &move 1, mut b: MoveRef<u8> = &move *Box::new(2), c = new::of(3),);
+            *b += 1;
+            assert_eq!((1, 3, 3), (*a, *b, *c));
+        }
+
+        #[test]
+        fn bind_try() {
+            fn make<N: new::TryNew<Output = u8>>(con: N) -> Result<u8, N::Error> {
+                bind!(mut x = try con, y = &move 1);
+                *x += *y;
+                return Ok(*x);
+            }
+
+            assert_eq!(Ok(2), make(new::of(1)));
+            assert_eq!(Err("failed"), make(crate::fixtures::Fail::new()));
+        }
+
+        #[test]
+        fn expr_try() {
+            let val = |con| return expr!(try con).map(|x| return *x);
+            assert_eq!(Ok::<_, core::convert::Infallible>(1), val(new::of(1)));
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn bind_tuple() {
+            bind!((a, mut b) = &move *Box::new((1, 2)));
+            *b += 1;
+            assert_eq!((1, 3), (*a, *b));
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn bind_tuple_nested() {
+            let tuple = ((1, Box::new(2)), 3);
+            bind!(((a, b), c) = &move tuple, d = &move 4);
+            let b: MoveRef<Box<u8>> = b;
+            assert_eq!((1, 2, 3, 4), (*a, **b, *c, *d));
+        }
+
+        #[test]
+        fn bind_tuple_wildcard() {
+            use core::sync::atomic::{AtomicUsize, Ordering};
+
+            use crate::fixtures::Counted;
+
+            static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+            bind!((a, _) = &move (1u8, 2u8));
+            assert_eq!(1, *a);
+            bind!(((_, b), _) = &move ((Counted::new(&DROPS), 2u8), Counted::new(&DROPS)));
+            assert_eq!(2, DROPS.load(Ordering::SeqCst));
+            assert_eq!(2, *b);
+        }
+
         #[test]
         fn trivial_copy() {
             let this = &true;
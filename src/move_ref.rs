@@ -3,7 +3,7 @@ use core::{
     pin::Pin,
 };
 
-use crate::slot_storage::SlotStorageStatus;
+use crate::{slot::Slot, slot_storage::SlotStorageStatus};
 
 /// A "reference" type which *uniquely* owns its referent type `T` with respect to external storage
 /// with lifetime `'frame`.
@@ -94,6 +94,34 @@ impl<'frame, T: ?Sized> MoveRef<'frame, T> {
         return Self { ptr, status };
     }
 
+    /// Create a [`MoveRef`] which owns the referent of `ptr`, using `storage` only to track the
+    /// status of the referent.
+    ///
+    /// This is the counterpart of [`MoveRef::release`], taking back ownership of a value which was
+    /// handed off, e.g., through a foreign API, so that it is again destroyed when the [`MoveRef`]
+    /// is dropped:
+    ///
+    /// ```
+    /// # use moveref::{bind, bind_slot, new, MoveRef};
+    /// bind!(x = new::of(5));
+    /// let ptr = MoveRef::release(x);
+    /// // ... pass `ptr` through a foreign API and back ...
+    /// bind_slot!(slot);
+    /// let x = unsafe { MoveRef::from_raw_in(ptr, slot) };
+    /// assert_eq!(5, *x);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must point to a valid `T` which is not owned by anything else
+    /// - `ptr` must remain valid for the lifetime `'frame`
+    /// - if the referent was pinned, the result must also be treated as pinned
+    #[inline]
+    pub unsafe fn from_raw_in(ptr: *mut T, storage: Slot<'frame, ()>) -> Self {
+        let (&mut (), status) = storage.write(());
+        return Self::new_unchecked(&mut *ptr, status);
+    }
+
     /// Transform a [`MoveRef<T>`] into a [`Pin<MoveRef<T>>`]. This is safe because the interface
     /// for [`MoveRef`] enforces that its referent will not be implicitly moved or have its storage
     /// invalidated until the [`MoveRef<T>`] (and its backing [`Slot`](crate::Slot)) is dropped.
@@ -113,6 +141,35 @@ impl<'frame, T: ?Sized> MoveRef<'frame, T> {
         unsafe { mov.status.release() };
         return mov.ptr;
     }
+
+    /// Consume a [`Pin<Self>`] and return the pinned referent for the rest of the lifetime
+    /// `'frame`. As with [`MoveRef::release`], this inhibits destruction of `T` by implicit
+    /// [`Drop`].
+    ///
+    /// # Safety
+    ///
+    /// - the referent must be destroyed before its backing storage is invalidated, since pinned
+    ///   data must not be invalidated without running its destructor
+    #[inline]
+    #[must_use]
+    pub unsafe fn release_pin(pin: Pin<Self>) -> Pin<&'frame mut T> {
+        let ptr = Self::release(pin);
+        return Pin::new_unchecked(&mut *ptr);
+    }
+
+    /// Return a pinned shared reference to the referent.
+    #[must_use]
+    #[inline]
+    pub fn as_pin_ref(pin: &Pin<Self>) -> Pin<&T> {
+        return pin.as_ref();
+    }
+
+    /// Return a pinned mutable reference to the referent.
+    #[must_use]
+    #[inline]
+    pub fn as_pin_mut(pin: &mut Pin<Self>) -> Pin<&mut T> {
+        return pin.as_mut();
+    }
 }
 
 impl<'frame, T> MoveRef<'frame, T> {
@@ -124,6 +181,38 @@ impl<'frame, T> MoveRef<'frame, T> {
         return unsafe { core::ptr::read(ptr) };
     }
 
+    /// Move the referent out of a [`Pin<Self>`], which is allowed since `T` is [`Unpin`].
+    #[must_use]
+    #[inline]
+    pub fn into_pin_inner(pin: Pin<Self>) -> T
+    where
+        T: Unpin,
+    {
+        return Pin::into_inner(pin).into_inner();
+    }
+
+    /// Move the referent out of the [`MoveRef`] and into a fresh [`Box`](crate::Box), so that it
+    /// can outlive the backing storage.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn into_box(self) -> crate::Box<T> {
+        return crate::Box::new(self.into_inner());
+    }
+
+    /// Move-construct the pinned referent into a fresh pinned [`Box`](crate::Box) using
+    /// [`MoveNew`](crate::MoveNew), so that it can outlive the backing storage without being moved
+    /// bitwise.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn into_pinned_box(pin: Pin<Self>) -> Pin<crate::Box<T>>
+    where
+        T: crate::MoveNew,
+    {
+        return <crate::Box<T> as crate::Emplace<T>>::emplace(crate::new::mov(pin));
+    }
+
     #[must_use]
     #[inline]
     pub fn as_ptr(&self) -> *const T {
@@ -232,6 +321,77 @@ mod test {
         assert_eq!(*y, 5);
     }
 
+    #[test]
+    fn move_ref_pin_accessors() {
+        bind!(mut x = new::of(5));
+        *MoveRef::as_pin_mut(&mut x) += 1;
+        assert_eq!(6, *MoveRef::as_pin_ref(&x));
+        assert_eq!(6, MoveRef::into_pin_inner(x));
+    }
+
+    #[test]
+    fn move_ref_from_raw_in() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::fixtures::Counted;
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        bind!(x = new::of(Counted::new(&DROPS)));
+        let ptr = MoveRef::release(x);
+        {
+            bind_slot!(slot);
+            let _x = unsafe { MoveRef::from_raw_in(ptr, slot) };
+        }
+        assert_eq!(1, DROPS.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn move_ref_release_pin() {
+        bind!(x = new::of(5));
+        let pin = unsafe { MoveRef::release_pin(x) };
+        assert_eq!(5, *pin);
+        unsafe { core::ptr::drop_in_place(core::ptr::from_mut(Pin::into_inner_unchecked(pin))) };
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn move_ref_into_box() {
+        bind!(x = &move 5);
+        let y = x.into_box();
+        assert_eq!(*y, 5);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn move_ref_into_pinned_box() {
+        struct Pinned {
+            val: u32,
+            _pinned: core::marker::PhantomPinned,
+        }
+
+        impl MoveNew for Pinned {
+            unsafe fn move_new(
+                src: Pin<MoveRef<Self>>,
+                dst: Pin<&mut core::mem::MaybeUninit<Self>>,
+            ) {
+                let dst = Pin::into_inner_unchecked(dst);
+                dst.write(Self {
+                    val: src.val,
+                    _pinned: core::marker::PhantomPinned,
+                });
+            }
+        }
+
+        let new = crate::new::of(Pinned {
+            val: 5,
+            _pinned: core::marker::PhantomPinned,
+        });
+        bind!(x = new);
+        let y = MoveRef::into_pinned_box(x);
+        assert_eq!(y.val, 5);
+    }
+
     #[test]
     #[should_panic(expected = "a critical reference counter at")]
     fn forget_move_ref() {
@@ -249,6 +409,7 @@ mod test {
     #[cfg(all(feature = "alloc", not(feature = "valgrind")))]
     #[test]
     #[should_panic(expected = "a critical reference counter at")]
+    #[allow(clippy::ptr_as_ptr)]
     fn forget_deref_moved_box() {
         let mut x = crate::Box::new(5);
         let ptr = x.as_mut() as *mut i32;
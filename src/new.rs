@@ -65,6 +65,156 @@ pub trait MoveNew: Sized {
     unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>);
 }
 
+impl<T: CopyNew, const N: usize> CopyNew for [T; N] {
+    #[inline]
+    unsafe fn copy_new(src: &Self, dst: Pin<&mut MaybeUninit<Self>>) {
+        let dst = Pin::into_inner_unchecked(dst).as_mut_ptr();
+        let slice = &mut *dst.cast::<[MaybeUninit<T>; N]>();
+        let con = |i: usize| {
+            return by_raw(move |this: Pin<&mut MaybeUninit<T>>| T::copy_new(&src[i], this));
+        };
+        match try_init_slice(Pin::new_unchecked(slice), con) {
+            | Ok(()) => {},
+            | Err(err) => match err {},
+        }
+    }
+}
+
+impl<T: MoveNew, const N: usize> MoveNew for [T; N] {
+    #[inline]
+    unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+        /// Helper type for destroying the source elements which have not been moved yet if
+        /// construction unwinds.
+        struct Suffix<T> {
+            /// The first source element.
+            ptr: *mut T,
+            /// The index of the first source element which has not been moved yet.
+            start: core::cell::Cell<usize>,
+            /// The number of source elements.
+            len: usize,
+        }
+
+        impl<T> Drop for Suffix<T> {
+            #[inline]
+            fn drop(&mut self) {
+                let start = self.start.get();
+                let rest = core::ptr::slice_from_raw_parts_mut(
+                    unsafe { self.ptr.add(start) },
+                    self.len - start,
+                );
+                unsafe { core::ptr::drop_in_place(rest) };
+            }
+        }
+
+        let suffix = Suffix {
+            ptr: MoveRef::release(src).cast::<T>(),
+            start: core::cell::Cell::new(0),
+            len: N,
+        };
+        let dst = Pin::into_inner_unchecked(dst).as_mut_ptr();
+        let slice = &mut *dst.cast::<[MaybeUninit<T>; N]>();
+        let con = |i: usize| {
+            let suffix = &suffix;
+            return by_raw(move |this: Pin<&mut MaybeUninit<T>>| {
+                suffix.start.set(i + 1);
+                bind_slot!(storage);
+                let src = MoveRef::from_raw_in(suffix.ptr.add(i), storage).into_pin();
+                T::move_new(src, this);
+            });
+        };
+        match try_init_slice(Pin::new_unchecked(slice), con) {
+            | Ok(()) => {},
+            | Err(err) => match err {},
+        }
+        core::mem::forget(suffix);
+    }
+}
+
+/// Helper type for destroying the fields `start .. end` of a tuple in reverse order if element-wise
+/// construction unwinds.
+struct TupleFields<'a, T> {
+    /// The tuple whose fields are destroyed.
+    ptr: *mut T,
+    /// The function destroying each field, by index.
+    drops: &'a [fn(*mut T)],
+    /// The index of the first field to destroy.
+    start: usize,
+    /// The index past the last field to destroy.
+    end: usize,
+}
+
+impl<T> Drop for TupleFields<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        for i in (self.start .. self.end).rev() {
+            (self.drops[i])(self.ptr);
+        }
+    }
+}
+
+/// Boilerplate macro for defining element-wise [`CopyNew`] and [`MoveNew`] instances for tuples.
+macro_rules! tuple_copy_move {
+    ($(($($name:ident $idx:tt),+)),* $(,)?) => {
+        $(
+            impl<$($name: CopyNew),+> CopyNew for ($($name,)+) {
+                #[inline]
+                unsafe fn copy_new(src: &Self, dst: Pin<&mut MaybeUninit<Self>>) {
+                    let dst = Pin::into_inner_unchecked(dst).as_mut_ptr();
+                    let drops: &[fn(*mut Self)] = &[$(|ptr| unsafe {
+                        core::ptr::drop_in_place(core::ptr::addr_of_mut!((*ptr).$idx));
+                    }),+];
+                    let mut prefix = TupleFields { ptr: dst, drops, start: 0, end: 0 };
+                    $(
+                        let this = core::ptr::addr_of_mut!((*dst).$idx).cast::<MaybeUninit<$name>>();
+                        $name::copy_new(&src.$idx, Pin::new_unchecked(&mut *this));
+                        prefix.end += 1;
+                    )+
+                    core::mem::forget(prefix);
+                }
+            }
+
+            impl<$($name: MoveNew),+> MoveNew for ($($name,)+) {
+                #[inline]
+                unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+                    let src = MoveRef::release(src);
+                    let dst = Pin::into_inner_unchecked(dst).as_mut_ptr();
+                    let drops: &[fn(*mut Self)] = &[$(|ptr| unsafe {
+                        core::ptr::drop_in_place(core::ptr::addr_of_mut!((*ptr).$idx));
+                    }),+];
+                    let mut suffix = TupleFields { ptr: src, drops, start: 0, end: drops.len() };
+                    let mut prefix = TupleFields { ptr: dst, drops, start: 0, end: 0 };
+                    $(
+                        suffix.start += 1;
+                        bind_slot!(storage);
+                        let that = core::ptr::addr_of_mut!((*src).$idx);
+                        let that = MoveRef::from_raw_in(that, storage).into_pin();
+                        let this = core::ptr::addr_of_mut!((*dst).$idx).cast::<MaybeUninit<$name>>();
+                        $name::move_new(that, Pin::new_unchecked(&mut *this));
+                        prefix.end += 1;
+                    )+
+                    core::mem::forget(prefix);
+                    core::mem::forget(suffix);
+                }
+            }
+        )*
+    };
+}
+
+tuple_copy_move! {
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11),
+}
+
 /// Constructs a [`New`] value using a thunk which initializes its data into some pinned,
 /// uninitialized memory.
 ///
@@ -102,6 +252,80 @@ where
     };
 }
 
+/// Constructs a [`TryNew`] value using a thunk which tries to initialize its data into some pinned,
+/// uninitialized memory.
+///
+/// # Safety
+///
+/// - `initializer` must satisfy the same safety requirements as [`TryNew::try_new()`]
+#[inline]
+pub unsafe fn try_by_raw<T, E, F>(initializer: F) -> impl TryNew<Output = T, Error = E>
+where
+    F: FnOnce(Pin<&mut MaybeUninit<T>>) -> Result<(), E>,
+{
+    /// Helper type for converting into the abstract `impl TryNew`.
+    struct TryFnNew<F, T, E> {
+        /// The underlying thunk.
+        initializer: F,
+        /// Phantom type holding `T` and `E`, respecting variance.
+        #[allow(clippy::type_complexity)]
+        _type: core::marker::PhantomData<fn(Pin<&mut MaybeUninit<T>>) -> E>,
+    }
+
+    #[rustfmt::skip]
+    impl<F, T, E> TryNew for TryFnNew<F, T, E> // tarpaulin
+    where
+        F: FnOnce(Pin<&mut MaybeUninit<T>>) -> Result<(), E>,
+    {
+        type Output = T; // tarpaulin
+        type Error = E; // tarpaulin
+        #[inline]
+        unsafe fn try_new(self, this: Pin<&mut MaybeUninit<Self::Output>>) -> Result<(), Self::Error> {
+            return (self.initializer)(this);
+        }
+    }
+
+    return TryFnNew {
+        initializer,                      // tarpaulin
+        _type: core::marker::PhantomData, // tarpaulin
+    };
+}
+
+/// Constructs a [`TryNew`] value which runs `f` on the pinned value once `new` has initialized it
+/// in the eventually provided storage.
+///
+/// If `f` panics, the initialized value is destroyed in place before unwinding continues.
+#[inline]
+pub(crate) fn with<N, F>(new: N, f: F) -> impl TryNew<Output = N::Output, Error = N::Error>
+where
+    N: TryNew,
+    F: FnOnce(Pin<&mut N::Output>),
+{
+    /// Helper type for destroying the initialized value if `f` panics.
+    struct DropGuard<T> {
+        /// The initialized value.
+        ptr: *mut T,
+    }
+
+    impl<T> Drop for DropGuard<T> {
+        #[inline]
+        fn drop(&mut self) {
+            unsafe { core::ptr::drop_in_place(self.ptr) }
+        }
+    }
+
+    unsafe {
+        return try_by_raw(move |mut this| {
+            new.try_new(this.as_mut())?;
+            let ptr = this.get_unchecked_mut().as_mut_ptr();
+            let guard = DropGuard { ptr };
+            f(Pin::new_unchecked(&mut *ptr));
+            core::mem::forget(guard);
+            return Ok(());
+        });
+    }
+}
+
 /// Constructs a [`New`] value using a value-producing thunk `f`.
 #[inline]
 pub fn by<T, F>(f: F) -> impl New<Output = T>
@@ -124,6 +348,243 @@ pub fn default<T: Default>() -> impl New<Output = T> {
     return by(Default::default);
 }
 
+/// Constructs a [`TryNew`] value for a type `T` by deserializing it from `deserializer` and then
+/// moving it into the eventually provided storage.
+///
+/// See [`deserialize_in_place()`] for deserializing large values without first building them
+/// elsewhere.
+#[cfg(feature = "serde")]
+#[inline]
+pub fn deserialize<'de, T, D>(deserializer: D) -> impl TryNew<Output = T, Error = D::Error>
+where
+    T: serde::Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    unsafe {
+        return try_by_raw(move |this| {
+            let seed = crate::deserialize::DeserializeNew::new(this);
+            return serde::de::DeserializeSeed::deserialize(seed, deserializer);
+        });
+    }
+}
+
+/// Constructs a [`TryNew`] value for a type `T` by writing its default value into the eventually
+/// provided storage, then deserializing from `deserializer` in place with
+/// [`Deserialize::deserialize_in_place`](serde::Deserialize::deserialize_in_place).
+///
+/// The value is only built in place if its [`Deserialize`](serde::Deserialize) implementation
+/// overrides `deserialize_in_place` (as `serde` does for arrays, and `serde_derive` does with its
+/// `deserialize_in_place` feature); otherwise this behaves like [`deserialize()`]. If
+/// deserialization fails, the default value is destroyed before the error is returned.
+#[cfg(feature = "serde")]
+#[inline]
+pub fn deserialize_in_place<'de, T, D>(deserializer: D) -> impl TryNew<Output = T, Error = D::Error>
+where
+    T: serde::Deserialize<'de> + Default + Unpin,
+    D: serde::Deserializer<'de>,
+{
+    unsafe {
+        return try_by_raw(move |this: Pin<&mut MaybeUninit<T>>| {
+            let this = Pin::into_inner(this);
+            let place = this.write(T::default());
+            let result = T::deserialize_in_place(deserializer, place);
+            if result.is_err() {
+                this.assume_init_drop();
+            }
+            return result;
+        });
+    }
+}
+
+/// Constructs a [`New`] value for a C++ `std::string` holding a copy of `value`.
+#[cfg(feature = "cxx")]
+#[inline]
+pub fn cxx_string<S>(value: &S) -> impl New<Output = crate::InlineCxxString> + '_
+where
+    S: AsRef<[u8]> + ?Sized,
+{
+    unsafe {
+        return by_raw(move |this: Pin<&mut MaybeUninit<crate::InlineCxxString>>| {
+            let bytes = value.as_ref();
+            let ptr = Pin::into_inner_unchecked(this).as_mut_ptr().cast();
+            crate::cxx_ffi::string_new(ptr, bytes.as_ptr(), bytes.len());
+        });
+    }
+}
+
+/// Constructs a [`New`] value for a C++ `std::vector<T>` holding a copy of `values`.
+#[cfg(feature = "cxx")]
+#[inline]
+pub fn cxx_vector<T>(values: &[T]) -> impl New<Output = crate::InlineCxxVector<T>> + '_
+where
+    T: crate::InlineCxxVectorElement,
+{
+    unsafe {
+        return by_raw(
+            move |this: Pin<&mut MaybeUninit<crate::InlineCxxVector<T>>>| {
+                let ptr = Pin::into_inner_unchecked(this).as_mut_ptr().cast();
+                T::__new(ptr, values.as_ptr(), values.len());
+            },
+        );
+    }
+}
+
+/// Try to construct each element of `slice` in place with the initializer `f(i)` for its index `i`.
+///
+/// If some initializer fails (or panics), the already-constructed elements are destroyed in reverse
+/// order before returning (or unwinding).
+///
+/// # Safety
+///
+/// - the same safety requirements as [`TryNew::try_new()`] apply with respect to each element of
+///   `slice`
+#[inline]
+pub(crate) unsafe fn try_init_slice<T, C, F>(
+    slice: Pin<&mut [MaybeUninit<T>]>,
+    mut f: F,
+) -> Result<(), C::Error>
+where
+    C: TryNew<Output = T>,
+    F: FnMut(usize) -> C,
+{
+    /// Helper type for destroying the constructed prefix if construction fails or unwinds.
+    struct Prefix<T> {
+        /// The first element.
+        ptr: *mut T,
+        /// The number of constructed elements.
+        len: usize,
+    }
+
+    impl<T> Drop for Prefix<T> {
+        #[inline]
+        fn drop(&mut self) {
+            for i in (0 .. self.len).rev() {
+                unsafe { core::ptr::drop_in_place(self.ptr.add(i)) };
+            }
+        }
+    }
+
+    let slice = Pin::into_inner_unchecked(slice);
+    let mut prefix = Prefix {
+        ptr: slice.as_mut_ptr().cast::<T>(),
+        len: 0,
+    };
+    for (i, elem) in slice.iter_mut().enumerate() {
+        f(i).try_new(Pin::new_unchecked(elem))?;
+        prefix.len += 1;
+    }
+    core::mem::forget(prefix);
+    return Ok(());
+}
+
+/// Constructs a [`New`] value for an array `[T; N]` by constructing each element in place with the
+/// initializer `f(i)` for its index `i`.
+///
+/// If some initializer panics, the already-constructed elements are destroyed in reverse order.
+#[inline]
+pub fn array<T, const N: usize, C, F>(f: F) -> impl New<Output = [T; N]>
+where
+    C: New<Output = T>,
+    F: FnMut(usize) -> C,
+{
+    unsafe {
+        return by_raw(move |this: Pin<&mut MaybeUninit<[T; N]>>| {
+            let this = Pin::into_inner_unchecked(this).as_mut_ptr();
+            let slice = &mut *this.cast::<[MaybeUninit<T>; N]>();
+            match try_init_slice(Pin::new_unchecked(slice), f) {
+                | Ok(()) => {},
+                | Err(err) => match err {},
+            }
+        });
+    }
+}
+
+/// Constructs a [`New`] value for an array `[T; N]` by constructing each element in place with the
+/// initializer `f(i)` for its index `i`.
+///
+/// This is the same as [`array()`], named after [`core::array::from_fn`].
+#[inline]
+pub fn from_fn<T, const N: usize, C, F>(f: F) -> impl New<Output = [T; N]>
+where
+    C: New<Output = T>,
+    F: FnMut(usize) -> C,
+{
+    return array(f);
+}
+
+/// Constructs a [`TryNew`] value for an array `[T; N]` by trying to construct each element in place
+/// with the initializer `f(i)` for its index `i`.
+///
+/// If some initializer fails (or panics), the already-constructed elements are destroyed in reverse
+/// order.
+#[inline]
+pub fn try_array<T, const N: usize, C, F>(f: F) -> impl TryNew<Output = [T; N], Error = C::Error>
+where
+    C: TryNew<Output = T>,
+    F: FnMut(usize) -> C,
+{
+    unsafe {
+        return try_by_raw(move |this: Pin<&mut MaybeUninit<[T; N]>>| {
+            let this = Pin::into_inner_unchecked(this).as_mut_ptr();
+            let slice = &mut *this.cast::<[MaybeUninit<T>; N]>();
+            return try_init_slice(Pin::new_unchecked(slice), f);
+        });
+    }
+}
+
+/// Constructs a [`New`] value for an array `[T; N]` by copy-constructing each element in place from
+/// `val`.
+///
+/// If some copy-construction panics, the already-constructed elements are destroyed in reverse
+/// order.
+#[inline]
+pub fn repeat<T: CopyNew, const N: usize>(val: &T) -> impl New<Output = [T; N]> + '_ {
+    return array(move |_| return copy(val));
+}
+
+/// Constructs a [`New`] value for a [`SelfRef<T, F>`](crate::SelfRef) which constructs its value
+/// with `new`, then passes the final pinned address to `fixup` so it can repair its internal
+/// pointers.
+///
+/// The `fixup` is run again each time the [`SelfRef<T, F>`](crate::SelfRef) is moved.
+#[inline]
+pub fn self_ref<T, N, F>(new: N, fixup: F) -> impl New<Output = crate::SelfRef<T, F>>
+where
+    N: New<Output = T>,
+    F: Fn(Pin<&mut T>),
+{
+    unsafe {
+        return by_raw(move |this: Pin<&mut MaybeUninit<crate::SelfRef<T, F>>>| {
+            let this = Pin::into_inner_unchecked(this).as_mut_ptr();
+            crate::SelfRef::new_in(new, fixup, this);
+        });
+    }
+}
+
+/// Constructs a [`New`] value for a [`Foreign<T>`](crate::Foreign) by calling a foreign constructor
+/// function, such as `void foo_init(foo_t *out, ...)` in C, with the eventually provided storage.
+///
+/// # Safety
+///
+/// - `init` must fully initialize the value at the pointer it is given, such that it may later be
+///   destroyed by [`ForeignDrop`](crate::ForeignDrop)
+#[inline]
+pub const unsafe fn from_c<T, F>(init: F) -> crate::CNew<T, F>
+where
+    T: crate::ForeignDrop,
+    F: FnOnce(*mut T),
+{
+    return crate::CNew::new_unchecked(init);
+}
+
+/// Constructs a [`New`] value which copy-constructs from `src` with [`CopyNew`].
+#[inline]
+pub fn copy<T: CopyNew>(src: &T) -> impl New<Output = T> + '_ {
+    unsafe {
+        return by_raw(move |dst| T::copy_new(src, dst));
+    }
+}
+
 /// Constructs a [`New`] value from a *uniquely* owning pointer `P` by moving its referent into the
 /// eventually provided storage.
 #[inline]
@@ -145,6 +606,29 @@ where
     }
 }
 
+/// Constructs a [`New`] value from a *uniquely* owning pointer `P` by relocating its [`Unpin`]
+/// referent into the eventually provided storage.
+///
+/// Unlike [`mov`], this does not require a [`MoveNew`] implementation for the referent, since any
+/// [`Unpin`] value can be moved bitwise.
+#[inline]
+pub fn mov_unpin<P>(ptr: P) -> impl New<Output = P::Target>
+where
+    P: IntoMove,
+    P::Target: Sized + Unpin,
+{
+    unsafe {
+        return by_raw(move |dst| {
+            bind_slot!(
+                #[dropping]
+                storage
+            );
+            let src = Pin::into_inner(ptr.into_move(storage));
+            Pin::into_inner(dst).write(src.into_inner());
+        });
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -173,4 +657,240 @@ mod test {
         bind!(pinned = Pinned::new());
         let _pinned = crate::new::mov(pinned);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn mov_containers() {
+        use alloc::{string::String, vec};
+
+        bind!(x = &move (String::from("a"), vec![1u8, 2], [String::from("b")]));
+        bind!(y = super::mov(x));
+        assert_eq!("a", y.0);
+        assert_eq!([1, 2], *y.1);
+        assert_eq!(["b"], y.2);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn copy_containers() {
+        use alloc::{string::String, vec};
+
+        let x = (String::from("a"), vec![1u8, 2], [String::from("b")]);
+        bind!(y = super::copy(&x));
+        assert_eq!(x, *y);
+    }
+
+    #[test]
+    fn mov_array_drops_once() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::fixtures::Counted;
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        {
+            let counted = || return Counted::new(&DROPS);
+            bind!(x = &move ([counted(), counted()], counted()));
+            bind!(_y = super::mov(x));
+        }
+        assert_eq!(3, DROPS.load(Ordering::SeqCst));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn copy_and_mov_unwind_drop_each_element_once() {
+        use core::cell::RefCell;
+        use std::{panic, vec::Vec};
+
+        struct Logged<'a>(usize, &'a RefCell<Vec<usize>>);
+        impl Drop for Logged<'_> {
+            fn drop(&mut self) {
+                self.1.borrow_mut().push(self.0);
+            }
+        }
+        impl CopyNew for Logged<'_> {
+            unsafe fn copy_new(src: &Self, dst: Pin<&mut MaybeUninit<Self>>) {
+                assert_ne!(2, src.0);
+                Pin::into_inner_unchecked(dst).write(Logged(src.0, src.1));
+            }
+        }
+        impl MoveNew for Logged<'_> {
+            unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+                assert_ne!(2, src.0);
+                Pin::into_inner_unchecked(dst).write(MoveRef::into_pin_inner(src));
+            }
+        }
+
+        let log = RefCell::new(Vec::new());
+        let unwind = |f: &dyn Fn()| {
+            log.borrow_mut().clear();
+            assert!(panic::catch_unwind(panic::AssertUnwindSafe(f)).is_err());
+            return log.replace(Vec::new());
+        };
+        let logged = |i| return Logged(i, &log);
+
+        let src = [logged(0), logged(1), logged(2), logged(3)];
+        assert_eq!(
+            [1, 0],
+            *unwind(&|| {
+                bind_slot!(slot);
+                slot.emplace(super::copy(&src));
+            })
+        );
+        drop(src);
+        assert_eq!(
+            [2, 1, 0, 3],
+            *unwind(&|| {
+                bind!(x = &move [logged(0), logged(1), logged(2), logged(3)]);
+                bind!(_y = super::mov(x));
+            })
+        );
+
+        let src = (logged(0), logged(1), logged(2));
+        assert_eq!(
+            [1, 0],
+            *unwind(&|| {
+                bind_slot!(slot);
+                slot.emplace(super::copy(&src));
+            })
+        );
+        drop(src);
+        assert_eq!(
+            [2, 1, 0],
+            *unwind(&|| {
+                bind!(x = &move (logged(0), logged(1), logged(2)));
+                bind!(_y = super::mov(x));
+            })
+        );
+    }
+
+    #[test]
+    fn mov_unpin() {
+        struct Unlisted(u32);
+
+        bind!(x = &move Unlisted(5));
+        bind!(y = super::mov_unpin(x));
+        assert_eq!(5, y.0);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn mov_unpin_box() {
+        struct Unlisted(u32);
+
+        let x = crate::Box::new(Unlisted(5));
+        bind!(y = super::mov_unpin(x));
+        assert_eq!(5, y.0);
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn ctor() {
+        use core::marker::PhantomPinned;
+
+        struct Point {
+            x: u32,
+            y: u32,
+            _pinned: PhantomPinned,
+        }
+
+        impl Point {
+            #[crate::ctor]
+            unsafe fn new(this: Pin<&mut MaybeUninit<Self>>, x: u32, y: u32) {
+                Pin::into_inner_unchecked(this).write(Self {
+                    x,
+                    y,
+                    _pinned: PhantomPinned,
+                });
+            }
+
+            #[crate::ctor]
+            unsafe fn parse<'a>(
+                this: Pin<&mut MaybeUninit<Self>>,
+                x: &'a str,
+                y: &'a str,
+            ) -> Result<(), core::num::ParseIntError> {
+                let x = x.parse()?;
+                let y = y.parse()?;
+                Self::new(x, y).new(this);
+                return Ok(());
+            }
+        }
+
+        bind!(p = Point::new(1, 2));
+        assert_eq!((1, 2), (p.x, p.y));
+        let parse = |x, y| -> Result<(u32, u32), core::num::ParseIntError> {
+            bind!(p = try Point::parse(x, y));
+            return Ok((p.x, p.y));
+        };
+        assert_eq!(Ok((3, 4)), parse("3", "4"));
+        assert!(parse("3", "four").is_err());
+    }
+
+    #[test]
+    fn array() {
+        bind!(x = super::array::<_, 4, _, _>(|i| return of(i * 2)));
+        assert_eq!([0, 2, 4, 6], *x);
+    }
+
+    #[test]
+    fn from_fn() {
+        bind!(x = super::from_fn::<_, 3, _, _>(|i| return of(i + 1)));
+        assert_eq!([1, 2, 3], *x);
+    }
+
+    #[test]
+    fn try_array() {
+        let even = |i| unsafe {
+            return try_by_raw(move |this: Pin<&mut MaybeUninit<usize>>| {
+                if i % 2 == 1 {
+                    return Err(i);
+                }
+                Pin::into_inner_unchecked(this).write(i);
+                return Ok(());
+            });
+        };
+        bind_slot!(slot);
+        assert_eq!(
+            Err(1),
+            slot.try_emplace(super::try_array::<_, 4, _, _>(even))
+                .map(|_| ())
+        );
+        bind_slot!(slot);
+        let x = slot.try_emplace(super::try_array::<_, 1, _, _>(even));
+        assert_eq!(Ok([0]), x.map(|x| return *x));
+    }
+
+    #[test]
+    fn try_array_drops_prefix() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        use crate::fixtures::Counted;
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        let con = |i| unsafe {
+            return try_by_raw(move |this: Pin<&mut MaybeUninit<Counted>>| {
+                if i == 2 {
+                    return Err(());
+                }
+                Pin::into_inner_unchecked(this).write(Counted::new(&DROPS));
+                return Ok(());
+            });
+        };
+        bind_slot!(slot);
+        assert!(
+            slot.try_emplace(super::try_array::<_, 3, _, _>(con))
+                .is_err()
+        );
+        assert_eq!(2, DROPS.load(Ordering::SeqCst));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn repeat() {
+        let val = alloc::string::String::from("a");
+        bind!(x = super::repeat::<_, 3>(&val));
+        assert_eq!(["a", "a", "a"], *x);
+    }
 }
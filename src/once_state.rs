@@ -0,0 +1,95 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// State of a value which has not been constructed.
+const UNINITIALIZED: u8 = 0;
+/// State of a value which is being constructed by some thread.
+const INITIALIZING: u8 = 1;
+/// State of a value which has been constructed.
+const INITIALIZED: u8 = 2;
+
+/// State for constructing a value at most once, shared by the thread-safe lazily-constructed types.
+///
+/// Callers which find construction in progress on another thread wait for it to finish. With the
+/// `std` feature, waiting yields to the scheduler so that the constructing thread can make progress
+/// even on a single core. Without it, waiting is a busy-wait using [`core::hint::spin_loop`].
+pub(crate) struct OnceState {
+    /// The construction state, one of [`UNINITIALIZED`], [`INITIALIZING`], or [`INITIALIZED`].
+    state: AtomicU8,
+}
+
+impl OnceState {
+    /// Construct a new, uninitialized [`OnceState`].
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        return Self {
+            state: AtomicU8::new(UNINITIALIZED), // tarpaulin
+        };
+    }
+
+    /// Return whether the value has been constructed.
+    #[inline]
+    pub(crate) fn is_initialized(&self) -> bool {
+        return self.state.load(Ordering::Acquire) == INITIALIZED;
+    }
+
+    /// Return whether the value has been constructed, given exclusive access.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) fn is_initialized_mut(&mut self) -> bool {
+        return *self.state.get_mut() == INITIALIZED;
+    }
+
+    /// Run `init` to construct the value unless it has already been constructed, waiting for any
+    /// construction in progress on another thread to finish first.
+    ///
+    /// If `init` fails (or panics), the state is left uninitialized and a later call may try again.
+    /// Calling back into the same [`OnceState`] from `init` never returns.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `init` fails with an error.
+    #[inline]
+    pub(crate) fn call<E>(&self, init: impl FnOnce() -> Result<(), E>) -> Result<(), E> {
+        /// Helper type for resetting the state if construction fails or unwinds.
+        struct Reset<'a> {
+            /// The state to reset.
+            state: &'a AtomicU8,
+        }
+
+        impl Drop for Reset<'_> {
+            #[inline]
+            fn drop(&mut self) {
+                self.state.store(UNINITIALIZED, Ordering::Release);
+            }
+        }
+
+        loop {
+            let exchange = self.state.compare_exchange_weak(
+                UNINITIALIZED,
+                INITIALIZING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            );
+            match exchange {
+                | Ok(_) => {
+                    let reset = Reset { state: &self.state };
+                    init()?;
+                    core::mem::forget(reset);
+                    self.state.store(INITIALIZED, Ordering::Release);
+                    return Ok(());
+                },
+                | Err(INITIALIZED) => return Ok(()),
+                | Err(_) => wait(),
+            }
+        }
+    }
+}
+
+/// Wait briefly for construction in progress on another thread.
+#[inline]
+fn wait() {
+    #[cfg(feature = "std")]
+    std::thread::yield_now();
+    #[cfg(not(feature = "std"))]
+    core::hint::spin_loop();
+}
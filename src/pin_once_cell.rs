@@ -0,0 +1,174 @@
+use core::{cell::UnsafeCell, marker::PhantomPinned, mem::MaybeUninit, pin::Pin};
+
+use crate::{
+    new::{New, TryNew},
+    once_state::OnceState,
+};
+
+/// A thread-safe cell whose value is constructed in place at most once.
+///
+/// This is similar to `std::sync::OnceLock`, except that the value is constructed directly in the
+/// storage of the cell by a [`New`] or [`TryNew`] initializer rather than being moved into place.
+/// Since the value may be address-sensitive, the cell must be pinned before it can be initialized.
+///
+/// Concurrent callers wait, yielding to the scheduler, until the first caller finishes
+/// construction. If construction fails (or panics), the cell is left uninitialized and the next
+/// caller tries again. Calling back into the same [`PinOnceCell`] from its own initializer will
+/// therefore never return.
+pub struct PinOnceCell<T> {
+    /// The construction state.
+    state: OnceState,
+    /// The raw underlying (possibily uninitialized) storage memory.
+    memory: UnsafeCell<MaybeUninit<T>>,
+    /// Marker making the cell `!Unpin`, since its value must not move once constructed.
+    _pinned: PhantomPinned,
+}
+
+unsafe impl<T: Send + Sync> Sync for PinOnceCell<T> {
+}
+
+impl<T> Default for PinOnceCell<T> {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<T> Drop for PinOnceCell<T> {
+    #[inline]
+    fn drop(&mut self) {
+        if self.state.is_initialized_mut() {
+            unsafe { self.memory.get_mut().assume_init_drop() }
+        }
+    }
+}
+
+impl<T> PinOnceCell<T> {
+    /// Construct a new, uninitialized [`PinOnceCell<T>`].
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        return Self {
+            state: OnceState::new(),
+            memory: UnsafeCell::new(MaybeUninit::uninit()),
+            _pinned: PhantomPinned, // tarpaulin
+        };
+    }
+
+    /// Return the pinned value if it has been constructed.
+    #[must_use]
+    #[inline]
+    pub fn get(self: Pin<&Self>) -> Option<Pin<&T>> {
+        let this = self.get_ref();
+        if this.state.is_initialized() {
+            return Some(unsafe { this.get_unchecked() });
+        }
+        return None;
+    }
+
+    /// Return the pinned value, constructing it in place with `new` if it has not been constructed.
+    #[inline]
+    pub fn get_or_init<N: New<Output = T>>(self: Pin<&Self>, new: N) -> Pin<&T> {
+        match self.get_or_try_init(new) {
+            | Ok(pin) => return pin,
+            | Err(err) => match err {},
+        }
+    }
+
+    /// Return the pinned value, trying to construct it in place with `new` if it has not been
+    /// constructed.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Err` if the `new` initializer fails with an error.
+    #[inline]
+    pub fn get_or_try_init<N: TryNew<Output = T>>(
+        self: Pin<&Self>,
+        new: N,
+    ) -> Result<Pin<&T>, N::Error> {
+        let this = self.get_ref();
+        this.state.call(|| {
+            let memory = unsafe { &mut *this.memory.get() };
+            return unsafe { new.try_new(Pin::new_unchecked(memory)) };
+        })?;
+        return Ok(unsafe { this.get_unchecked() });
+    }
+
+    /// Return the pinned value, assuming it has been constructed.
+    ///
+    /// # Safety
+    ///
+    /// - the value must have been observed as initialized
+    #[inline]
+    unsafe fn get_unchecked(&self) -> Pin<&T> {
+        let ptr = (*self.memory.get()).assume_init_ref();
+        return Pin::new_unchecked(ptr);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{
+        mem::MaybeUninit,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use crate::*;
+
+    #[test]
+    fn get_or_init() {
+        let cell = core::pin::pin!(PinOnceCell::new());
+        let cell = cell.as_ref();
+        assert!(cell.get().is_none());
+        assert_eq!(42, *cell.get_or_init(new::of(42)));
+        assert_eq!(42, *cell.get_or_init(new::of(0)));
+        assert_eq!(Some(42), cell.get().map(|pin| return *pin));
+    }
+
+    #[test]
+    fn get_or_try_init_error() {
+        let cell = core::pin::pin!(PinOnceCell::new());
+        let cell = cell.as_ref();
+        assert!(cell.get_or_try_init(crate::fixtures::Fail::new()).is_err());
+        assert!(cell.get().is_none());
+        assert_eq!(
+            Ok(42),
+            cell.get_or_try_init(new::of(42)).map(|pin| return *pin)
+        );
+    }
+
+    #[test]
+    fn get_or_init_concurrent() {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        let cell = core::pin::pin!(PinOnceCell::new());
+        let cell = cell.as_ref();
+        std::thread::scope(|scope| {
+            for _ in 0 .. 8 {
+                scope.spawn(move || {
+                    let new = unsafe {
+                        new::by_raw(|mut this| {
+                            COUNT.fetch_add(1, Ordering::SeqCst);
+                            this.set(MaybeUninit::new(42));
+                        })
+                    };
+                    assert_eq!(42, *cell.get_or_init(new));
+                });
+            }
+        });
+        assert_eq!(1, COUNT.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn drop_initialized() {
+        use crate::fixtures::Counted;
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        {
+            let cell = core::pin::pin!(PinOnceCell::new());
+            cell.as_ref().get_or_init(new::of(Counted::new(&DROPS)));
+        }
+        assert_eq!(1, DROPS.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,160 @@
+use core::{marker::PhantomPinned, mem::MaybeUninit, ops::Deref, pin::Pin};
+
+use crate::{
+    move_ref::MoveRef,
+    new::{MoveNew, New},
+};
+
+/// A self-referential value paired with a fixup closure which repairs its internal pointers.
+///
+/// The fixup is run once the value is constructed at its final pinned address, and again each time
+/// the value is moved with [`MoveNew`], so that types such as intrusive list heads which point to
+/// themselves move correctly through [`new::mov`](crate::new::mov). The fixup is moved along with
+/// the value, so any state it captures must remain valid wherever the value is moved to.
+///
+/// This is created by [`new::self_ref`](crate::new::self_ref).
+#[allow(clippy::module_name_repetitions)]
+pub struct SelfRef<T, F = fn(Pin<&mut T>)> {
+    /// The self-referential value.
+    value: T,
+    /// The closure which repairs the internal pointers of `value` at its current address.
+    fixup: F,
+    /// Marker making the value `!Unpin`, since its internal pointers depend on its address.
+    _pinned: PhantomPinned,
+}
+
+impl<T, F> SelfRef<T, F>
+where
+    F: Fn(Pin<&mut T>),
+{
+    /// Return a pinned mutable reference to the self-referential value.
+    #[must_use]
+    #[inline]
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
+        unsafe { return self.map_unchecked_mut(|this| return &mut this.value) };
+    }
+
+    /// Construct a [`SelfRef`] in place at `this` from `new`, then run `fixup` at its final address.
+    ///
+    /// # Safety
+    ///
+    /// - `this` must be valid for writes of a [`SelfRef<T>`]
+    #[inline]
+    pub(crate) unsafe fn new_in<N>(new: N, fixup: F, this: *mut Self)
+    where
+        N: New<Output = T>,
+    {
+        let value = core::ptr::addr_of_mut!((*this).value);
+        new.new(Pin::new_unchecked(&mut *value.cast::<MaybeUninit<T>>()));
+        core::ptr::addr_of_mut!((*this).fixup).write(fixup);
+        ((*this).fixup)(Pin::new_unchecked(&mut *value));
+    }
+}
+
+impl<T, F> Deref for SelfRef<T, F> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        return &self.value;
+    }
+}
+
+impl<T, F> MoveNew for SelfRef<T, F>
+where
+    F: Fn(Pin<&mut T>),
+{
+    #[inline]
+    unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+        let src = MoveRef::release(src);
+        let dst = Pin::into_inner_unchecked(dst).as_mut_ptr();
+        // The value is relocated bitwise, leaving its internal pointers aimed at `src` until fixed.
+        core::ptr::copy_nonoverlapping(src, dst, 1);
+        ((*dst).fixup)(Pin::new_unchecked(&mut (*dst).value));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{
+        marker::PhantomPinned,
+        pin::Pin,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use crate::*;
+
+    struct Head {
+        next: *const Head,
+        val: u32,
+        _pinned: PhantomPinned,
+    }
+
+    impl Head {
+        fn fixup(this: Pin<&mut Self>) {
+            let this = unsafe { this.get_unchecked_mut() };
+            this.next = core::ptr::from_ref(this);
+        }
+
+        fn is_valid(&self) -> bool {
+            return core::ptr::eq(self.next, self);
+        }
+    }
+
+    fn head(val: u32) -> impl New<Output = SelfRef<Head>> {
+        let head = new::of(Head {
+            next: core::ptr::null(),
+            val,
+            _pinned: PhantomPinned,
+        });
+        return new::self_ref(head, Head::fixup as fn(Pin<&mut Head>));
+    }
+
+    #[test]
+    fn self_ref() {
+        bind!(head = head(42));
+        assert!(head.is_valid());
+        assert_eq!(42, head.val);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn self_ref_mov() {
+        bind!(head = head(42));
+        let boxed = Box::emplace(new::mov(head));
+        assert!(boxed.is_valid());
+        bind!(head = new::mov(boxed));
+        assert!(head.is_valid());
+        assert_eq!(42, head.val);
+    }
+
+    #[test]
+    fn self_ref_closure() {
+        static FIXUPS: AtomicUsize = AtomicUsize::new(0);
+
+        let fixups = &FIXUPS;
+        let head = new::of(Head {
+            next: core::ptr::null(),
+            val: 42,
+            _pinned: PhantomPinned,
+        });
+        let fixup = move |this: Pin<&mut Head>| {
+            fixups.fetch_add(1, Ordering::SeqCst);
+            Head::fixup(this);
+        };
+        bind!(head = new::self_ref(head, fixup));
+        assert!(head.is_valid());
+        bind!(head = new::mov(head));
+        assert!(head.is_valid());
+        assert_eq!(2, FIXUPS.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn self_ref_get_pin_mut() {
+        bind!(mut head = head(42));
+        let mut head = head.as_mut().get_pin_mut();
+        unsafe { head.as_mut().get_unchecked_mut().val = 7 };
+        assert!(head.is_valid());
+        assert_eq!(7, head.val);
+    }
+}
@@ -34,14 +34,49 @@ impl<'frame, T> Slot<'frame, T> {
         self,
         new: N,
     ) -> Result<Pin<MoveRef<'frame, T>>, N::Error> {
-        self.status.initialize();
         unsafe { new.try_new(Pin::new_unchecked(self.memory))? };
+        self.status.initialize();
         let ptr = unsafe { self.memory.assume_init_mut() };
         let mov = unsafe { MoveRef::new_unchecked(ptr, self.status) };
         let pin = mov.into_pin();
         return Ok(pin);
     }
 
+    /// Construct and pin `new` into the slot, run `f` on the pinned value, and return the associated
+    /// owning [`MoveRef`].
+    ///
+    /// This gives `f` access to the final address of the value before any other code can observe
+    /// it, e.g., to register the value with some manager.
+    #[inline]
+    pub fn emplace_with<N, F>(self, new: N, f: F) -> Pin<MoveRef<'frame, T>>
+    where
+        N: New<Output = T>,
+        F: FnOnce(Pin<&mut T>),
+    {
+        match self.try_emplace_with(new, f) {
+            | Ok(pin) => return pin,
+            | Err(err) => match err {},
+        }
+    }
+
+    /// Try to construct and pin `new` into the slot, run `f` on the pinned value, and return the
+    /// associated owning [`MoveRef`].
+    ///
+    /// # Errors
+    ///
+    /// Should return `Err` if the `new` initializer fails with an error, in which case `f` is not
+    /// run.
+    #[inline]
+    pub fn try_emplace_with<N, F>(self, new: N, f: F) -> Result<Pin<MoveRef<'frame, T>>, N::Error>
+    where
+        N: TryNew<Output = T>,
+        F: FnOnce(Pin<&mut T>),
+    {
+        let mut pin = self.try_emplace(new)?;
+        f(pin.as_mut());
+        return Ok(pin);
+    }
+
     /// Move and pin `val` into the slot and return the associated owning [`MoveRef`].
     #[inline]
     pub fn pin(self, val: T) -> Pin<MoveRef<'frame, T>> {
@@ -63,3 +98,36 @@ impl<'frame, T> Slot<'frame, T> {
         return (ptr, self.status);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn emplace_with() {
+        let mut addr = core::ptr::null();
+        bind_slot!(slot);
+        let val = slot.emplace_with(new::of(0u8), |mut pin| {
+            *pin = 1;
+            addr = core::ptr::from_ref(&*pin);
+        });
+        assert_eq!(1, *val);
+        assert_eq!(addr, core::ptr::from_ref(&*val));
+    }
+
+    #[test]
+    fn try_emplace_error_then_drop_storage() {
+        use crate::fixtures::Fail;
+
+        {
+            bind_slot!(slot);
+            assert_eq!(
+                Err("failed"),
+                slot.try_emplace(Fail::<u8>::new()).map(|_| ())
+            );
+        }
+        let mut storage = SlotStorage::new(SlotStorageKind::Keep);
+        assert!(storage.slot().try_emplace(Fail::<u8>::new()).is_err());
+        assert_eq!(SlotStorageState::Uninitialized, storage.state());
+    }
+}
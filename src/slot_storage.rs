@@ -2,14 +2,38 @@ use core::{cell::Cell, mem::MaybeUninit};
 
 use crate::slot::Slot;
 
+/// State of the storage for a [`Slot`].
+///
+/// The state advances along the following transitions:
+///
+/// - [`SlotStorageState::Uninitialized`] to [`SlotStorageState::Initialized`], when a value is
+///   placed into the [`Slot`] and the [`MoveRef`](crate::MoveRef) referencing it is created
+/// - [`SlotStorageState::Initialized`] to [`SlotStorageState::Terminated`], when the
+///   [`MoveRef`](crate::MoveRef) is dropped and destroys its referent
+/// - [`SlotStorageState::Initialized`] to [`SlotStorageState::Released`], when the
+///   [`MoveRef`](crate::MoveRef) is released and its referent is handed off to the caller
+/// - [`SlotStorageState::Terminated`] or [`SlotStorageState::Released`] back to
+///   [`SlotStorageState::Uninitialized`], when the storage is explicitly reset with
+///   [`SlotStorage::reset`]
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SlotStorageState {
+    /// No value has been placed into the storage.
+    Uninitialized,
+    /// A value has been placed into the storage and is referenced by a live
+    /// [`MoveRef`](crate::MoveRef).
+    Initialized,
+    /// The referencing [`MoveRef`](crate::MoveRef) was released. If released, [`Drop`] will be
+    /// skipped.
+    Released,
+    /// The referencing [`MoveRef`](crate::MoveRef) was dropped, destroying its referent.
+    Terminated,
+}
+
 /// State for tracking the status of a storage [`Slot`].
 pub(crate) struct SlotStorageTracker {
-    /// Whether the [`Slot`] is initialized.
-    initialized: Cell<bool>,
-    /// Whether the [`Slot`] is released. If released, [`Drop`] will be skipped.
-    released: Cell<bool>,
-    /// Number of references to the [`Slot`]. Used for checking various conditions.
-    references: Cell<usize>,
+    /// The current state of the [`Slot`].
+    state: Cell<SlotStorageState>,
 }
 
 impl SlotStorageTracker {
@@ -17,9 +41,7 @@ impl SlotStorageTracker {
     #[inline]
     pub const fn new() -> Self {
         return Self {
-            initialized: Cell::new(false),
-            released: Cell::new(false),
-            references: Cell::new(0),
+            state: Cell::new(SlotStorageState::Uninitialized),
         };
     }
 
@@ -27,9 +49,7 @@ impl SlotStorageTracker {
     #[inline]
     pub const fn status(&self) -> SlotStorageStatus<'_> {
         return SlotStorageStatus {
-            initialized: &self.initialized,
-            released: &self.released,
-            references: &self.references, // tarpaulin
+            state: &self.state, // tarpaulin
         };
     }
 }
@@ -37,79 +57,64 @@ impl SlotStorageTracker {
 /// The borrowed form of [`SlotStorageTracker`].
 #[derive(Clone, Copy)]
 pub(crate) struct SlotStorageStatus<'frame> {
-    /// Whether the [`Slot`] is initialized.
-    initialized: &'frame Cell<bool>,
-    /// Whether the [`Slot`] is released. If released, [`Drop`] will be skipped.
-    released: &'frame Cell<bool>,
-    /// Number of references to the [`Slot`]. Used for checking various conditions.
-    references: &'frame Cell<usize>,
+    /// The current state of the [`Slot`].
+    state: &'frame Cell<SlotStorageState>,
 }
 
 impl<'frame> SlotStorageStatus<'frame> {
-    /// Set the status to initialized.
-    #[inline]
-    pub(crate) fn initialize(&self) {
-        debug_assert!(!self.is_initialized());
-        self.initialized.set(true);
-        self.increment();
-    }
-
-    /// Increment the reference count.
+    /// Transition the state from uninitialized to initialized.
     #[inline]
-    pub(crate) fn increment(&self) {
-        debug_assert!(self.is_reference_zeroed());
-        self.references.set(self.references.get() + 1);
+    pub(crate) fn initialize(self) {
+        debug_assert!(self.is_uninitialized());
+        self.state.set(SlotStorageState::Initialized);
     }
 
-    /// Decrement the reference count.
+    /// Transition the state from initialized to released. Once marked as released, [`Drop`] will
+    /// be skipped.
     #[inline]
-    pub(crate) fn decrement(&self) {
-        debug_assert!(!self.is_reference_zeroed());
-        self.references.set(self.references.get() - 1);
+    pub(crate) unsafe fn release(self) {
+        debug_assert!(self.is_leaking());
+        self.state.set(SlotStorageState::Released);
     }
 
-    /// Mark the storage as released. Once marked as released, [`Drop`] will be skipped.
+    /// Transition the state from initialized to terminated. It is intended to be called only when
+    /// the referencing [`MoveRef`](crate::MoveRef) is dropped.
     #[inline]
-    pub(crate) unsafe fn release(&self) {
-        self.released.set(true);
+    pub(crate) fn terminate(self) {
+        debug_assert!(self.is_leaking());
+        self.state.set(SlotStorageState::Terminated);
     }
 
-    /// Mark the storage as terminated. This is just a decrement followed by an assertion that
-    /// references are finally zeroed. It is intended to be called only when the storage is dropped.
+    /// Transition the state from terminated or released back to uninitialized, so that the storage
+    /// can be reused.
     #[inline]
-    pub(crate) fn terminate(&self) {
-        self.decrement();
-        debug_assert!(self.is_reference_zeroed());
+    pub(crate) fn reset(self) {
+        debug_assert!(!self.is_leaking());
+        self.state.set(SlotStorageState::Uninitialized);
     }
 
-    /// Check if the storage is initialized.
+    /// Return the current state of the storage.
     #[inline]
-    pub(crate) fn is_initialized(&self) -> bool {
-        return self.initialized.get();
+    pub(crate) fn state(self) -> SlotStorageState {
+        return self.state.get();
     }
 
     /// Check if the storage is uninitialized.
     #[inline]
-    pub(crate) fn is_uninitialized(&self) -> bool {
-        return self.is_reference_zeroed() && !self.is_initialized();
+    pub(crate) fn is_uninitialized(self) -> bool {
+        return self.state() == SlotStorageState::Uninitialized;
     }
 
     /// Check if the storage is released.
     #[inline]
-    pub(crate) fn is_released(&self) -> bool {
-        return self.released.get();
+    pub(crate) fn is_released(self) -> bool {
+        return self.state() == SlotStorageState::Released;
     }
 
-    /// Check if the storage is leaking.
+    /// Check if the storage is leaking, i.e., still referenced by some [`MoveRef`](crate::MoveRef).
     #[inline]
-    pub(crate) fn is_leaking(&self) -> bool {
-        return !self.is_released() && self.is_initialized() && !self.is_reference_zeroed();
-    }
-
-    /// Check if the references are zeroed.
-    #[inline]
-    pub(crate) fn is_reference_zeroed(&self) -> bool {
-        return self.references.get() == 0;
+    pub(crate) fn is_leaking(self) -> bool {
+        return self.state() == SlotStorageState::Initialized;
     }
 }
 
@@ -168,6 +173,13 @@ impl<T> SlotStorage<T> {
         };
     }
 
+    /// Return the current state of the storage, for diagnostic purposes.
+    #[must_use]
+    #[inline]
+    pub fn state(&self) -> SlotStorageState {
+        return self.tracker.status().state();
+    }
+
     /// Project the [`Slot`] for the storage.
     #[inline]
     pub fn slot(&mut self) -> Slot<'_, T> {
@@ -176,6 +188,45 @@ impl<T> SlotStorage<T> {
         return Slot { memory, status };
     }
 
+    /// Reset the storage so that it can be reused for a fresh [`Slot`], returning whether the reset
+    /// succeeded.
+    ///
+    /// Resetting succeeds only once the [`MoveRef`](crate::MoveRef) for the previous value has
+    /// been dropped (destroying the value) or released, or if no value was ever placed into the
+    /// storage. Otherwise the storage is left untouched.
+    ///
+    /// Resetting ends the lifetime of the previous storage contents as if the storage had been
+    /// dropped, so pointers obtained from [`MoveRef::release`](crate::MoveRef::release) must no
+    /// longer be used afterwards.
+    #[must_use]
+    #[inline]
+    pub fn reset(&mut self) -> bool {
+        let status = self.tracker.status();
+        match status.state() {
+            | SlotStorageState::Uninitialized => return true,
+            | SlotStorageState::Initialized => return false,
+            | SlotStorageState::Released | SlotStorageState::Terminated => {
+                if matches!(self.kind, SlotStorageKind::Drop) {
+                    unsafe { self.memory.assume_init_drop() }
+                }
+                status.reset();
+                return true;
+            },
+        }
+    }
+
+    /// Reset the storage and project a fresh [`Slot`] for it, or return `None` if the storage could
+    /// not be reset.
+    ///
+    /// See [`SlotStorage::reset`] for the conditions under which resetting succeeds.
+    #[inline]
+    pub fn slot_again(&mut self) -> Option<Slot<'_, T>> {
+        if self.reset() {
+            return Some(self.slot());
+        }
+        return None;
+    }
+
     #[inline]
     pub fn display_location(&self) -> &dyn core::fmt::Display {
         /// Placeholder location display.
@@ -230,5 +281,228 @@ mod test {
             let storage = SlotStorage::<()>::new(kind);
             drop(storage);
         }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn slot_storage_reset_dropping() {
+            let kind = SlotStorageKind::Drop;
+            let mut storage = SlotStorage::new(kind);
+            for val in 0 .. 3 {
+                let slot = storage.slot_again().unwrap();
+                let mov = DerefMove::deref_move(Box::new(val), slot);
+                assert_eq!(val, *mov);
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod state {
+        use core::{
+            mem::MaybeUninit,
+            pin::Pin,
+            sync::atomic::{AtomicUsize, Ordering},
+        };
+        use std::{format, vec::Vec};
+
+        use proptest::{prelude::*, sample::select};
+
+        use super::*;
+        use crate::fixtures::Counted;
+
+        /// How a value is placed into the slot.
+        #[derive(Clone, Copy, Debug)]
+        enum Place {
+            Unused,
+            Emplace,
+            Put,
+            DerefMove,
+        }
+
+        /// How the [`MoveRef`] for the placed value is finished.
+        #[derive(Clone, Copy, Debug)]
+        enum Finish {
+            Drop,
+            Release,
+            IntoInner,
+            Forget,
+        }
+
+        /// Storage types which a [`Counted`] value can be placed into.
+        trait Placeable: Sized {
+            /// The kind of storage the value is placed into.
+            const KIND: SlotStorageKind;
+            /// The ways a value can be placed into the storage.
+            const PLACES: &'static [Place];
+
+            /// Place `val` into `slot`, or drop it and return `None` if `place` is unused.
+            fn place<'frame>(
+                slot: Slot<'frame, Self>,
+                val: Counted<'static>,
+                place: Place,
+            ) -> Option<Pin<MoveRef<'frame, Counted<'static>>>>;
+        }
+
+        impl Placeable for Counted<'static> {
+            const KIND: SlotStorageKind = SlotStorageKind::Keep;
+            const PLACES: &'static [Place] = &[Place::Unused, Place::Emplace, Place::Put];
+
+            fn place<'frame>(
+                slot: Slot<'frame, Self>,
+                val: Counted<'static>,
+                place: Place,
+            ) -> Option<Pin<MoveRef<'frame, Counted<'static>>>> {
+                match place {
+                    | Place::Emplace => return Some(slot.emplace(new::of(val))),
+                    | Place::Put => return Some(slot.put(val).into_pin()),
+                    | Place::Unused | Place::DerefMove => return None,
+                }
+            }
+        }
+
+        impl Placeable for Box<MaybeUninit<Counted<'static>>> {
+            const KIND: SlotStorageKind = SlotStorageKind::Drop;
+            const PLACES: &'static [Place] = &[Place::Unused, Place::DerefMove];
+
+            fn place<'frame>(
+                slot: Slot<'frame, Self>,
+                val: Counted<'static>,
+                place: Place,
+            ) -> Option<Pin<MoveRef<'frame, Counted<'static>>>> {
+                match place {
+                    | Place::DerefMove => {
+                        return Some(DerefMove::deref_move(Box::new(val), slot).into_pin());
+                    },
+                    | Place::Unused | Place::Emplace | Place::Put => return None,
+                }
+            }
+        }
+
+        fn finish() -> impl Strategy<Value = Finish> {
+            return prop_oneof![
+                Just(Finish::Drop),
+                Just(Finish::Release),
+                Just(Finish::IntoInner),
+                Just(Finish::Forget),
+            ];
+        }
+
+        fn steps<S: Placeable>() -> impl Strategy<Value = Vec<(Place, Finish)>> {
+            return proptest::collection::vec((select(S::PLACES), finish()), 0 .. 32);
+        }
+
+        /// Finish `mov` and return the expected resulting state and number of drops.
+        fn run(mov: Pin<MoveRef<Counted>>, finish: Finish) -> (SlotStorageState, usize) {
+            match finish {
+                | Finish::Drop => {
+                    drop(mov);
+                    return (SlotStorageState::Terminated, 1);
+                },
+                | Finish::Release => {
+                    let ptr = MoveRef::release(mov);
+                    unsafe { core::ptr::drop_in_place(ptr) };
+                    return (SlotStorageState::Released, 1);
+                },
+                | Finish::IntoInner => {
+                    drop(Pin::into_inner(mov).into_inner());
+                    return (SlotStorageState::Released, 1);
+                },
+                | Finish::Forget => {
+                    core::mem::forget(mov);
+                    return (SlotStorageState::Initialized, 0);
+                },
+            }
+        }
+
+        /// Place a value into `slot` and finish it, returning the expected resulting state and
+        /// number of drops.
+        fn step<S: Placeable>(
+            slot: Slot<'_, S>,
+            drops: &'static AtomicUsize,
+            place: Place,
+            finish: Finish,
+        ) -> (SlotStorageState, usize) {
+            match S::place(slot, Counted::new(drops), place) {
+                | Some(mov) => return run(mov, finish),
+                | None => return (SlotStorageState::Uninitialized, 1),
+            }
+        }
+
+        /// Check each step against a fresh storage.
+        fn transitions<S: Placeable>(
+            drops: &'static AtomicUsize,
+            steps: Vec<(Place, Finish)>,
+        ) -> Result<(), TestCaseError> {
+            for (place, finish) in steps {
+                let before = drops.load(Ordering::SeqCst);
+                let mut storage = SlotStorage::<S>::new(S::KIND);
+                prop_assert_eq!(SlotStorageState::Uninitialized, storage.state());
+                let (state, dropped) = step(storage.slot(), drops, place, finish);
+                prop_assert_eq!(state, storage.state());
+                prop_assert_eq!(before + dropped, drops.load(Ordering::SeqCst));
+                if state == SlotStorageState::Initialized {
+                    // NOTE: forgetting the `MoveRef` leaves the storage leaking, which would abort
+                    // on drop, so the storage must be forgotten as well.
+                    core::mem::forget(storage);
+                } else {
+                    drop(storage);
+                    prop_assert_eq!(before + dropped, drops.load(Ordering::SeqCst));
+                }
+            }
+            return Ok(());
+        }
+
+        /// Check each step against the same storage, reset between steps.
+        fn transitions_with_reset<S: Placeable>(
+            drops: &'static AtomicUsize,
+            steps: Vec<(Place, Finish)>,
+        ) -> Result<(), TestCaseError> {
+            let mut storage = SlotStorage::<S>::new(S::KIND);
+            for (place, finish) in steps {
+                let before = drops.load(Ordering::SeqCst);
+                prop_assert!(storage.reset());
+                prop_assert_eq!(SlotStorageState::Uninitialized, storage.state());
+                let slot = storage.slot_again();
+                prop_assert!(slot.is_some());
+                let (state, dropped) = step(slot.unwrap(), drops, place, finish);
+                prop_assert_eq!(state, storage.state());
+                prop_assert_eq!(before + dropped, drops.load(Ordering::SeqCst));
+                if state == SlotStorageState::Initialized {
+                    prop_assert!(!storage.reset());
+                    prop_assert!(storage.slot_again().is_none());
+                    prop_assert_eq!(SlotStorageState::Initialized, storage.state());
+                    // NOTE: see above.
+                    core::mem::forget(storage);
+                    return Ok(());
+                }
+            }
+            drop(storage);
+            return Ok(());
+        }
+
+        proptest! {
+            #[test]
+            fn transitions_keep(steps in steps::<Counted>()) {
+                static DROPS: AtomicUsize = AtomicUsize::new(0);
+                transitions::<Counted>(&DROPS, steps)?;
+            }
+
+            #[test]
+            fn transitions_drop(steps in steps::<Box<MaybeUninit<Counted>>>()) {
+                static DROPS: AtomicUsize = AtomicUsize::new(0);
+                transitions::<Box<MaybeUninit<Counted>>>(&DROPS, steps)?;
+            }
+
+            #[test]
+            fn transitions_with_reset_keep(steps in steps::<Counted>()) {
+                static DROPS: AtomicUsize = AtomicUsize::new(0);
+                transitions_with_reset::<Counted>(&DROPS, steps)?;
+            }
+
+            #[test]
+            fn transitions_with_reset_drop(steps in steps::<Box<MaybeUninit<Counted>>>()) {
+                static DROPS: AtomicUsize = AtomicUsize::new(0);
+                transitions_with_reset::<Box<MaybeUninit<Counted>>>(&DROPS, steps)?;
+            }
+        }
     }
 }
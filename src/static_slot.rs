@@ -0,0 +1,152 @@
+use core::{cell::UnsafeCell, mem::MaybeUninit, pin::Pin};
+
+use crate::{
+    new::{New, TryNew},
+    once_state::OnceState,
+};
+
+/// Storage for a `'static` value which is constructed in place at most once.
+///
+/// This is similar to `std::sync::OnceLock`, except that the value is constructed directly in the
+/// storage of the [`StaticSlot`] by a [`New`] initializer rather than being moved into place. This
+/// makes it suitable for global singletons of address-sensitive types, such as C++ objects.
+///
+/// Access requires a `&'static StaticSlot<T>`, as obtained from a `static` item or from leaked
+/// storage, which guarantees that the value is never moved or invalidated once it is constructed.
+/// For the same reason, the value is never dropped.
+///
+/// Concurrent callers wait for the first caller to finish construction. With the `std` feature they
+/// yield to the scheduler while waiting; otherwise they busy-wait, so on a single core the
+/// constructing thread must be able to preempt them. Calling back into the same [`StaticSlot`] from
+/// its own initializer will therefore never return.
+pub struct StaticSlot<T> {
+    /// The construction state.
+    state: OnceState,
+    /// The raw underlying (possibily uninitialized) storage memory.
+    memory: UnsafeCell<MaybeUninit<T>>,
+}
+
+unsafe impl<T: Send + Sync> Sync for StaticSlot<T> {
+}
+
+impl<T> Default for StaticSlot<T> {
+    #[inline]
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<T> StaticSlot<T> {
+    /// Construct a new, uninitialized [`StaticSlot<T>`].
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        return Self {
+            state: OnceState::new(),
+            memory: UnsafeCell::new(MaybeUninit::uninit()), // tarpaulin
+        };
+    }
+
+    /// Return the pinned value if it has been constructed.
+    #[must_use]
+    #[inline]
+    pub fn get(&'static self) -> Option<Pin<&'static T>> {
+        if self.state.is_initialized() {
+            return Some(unsafe { self.get_unchecked() });
+        }
+        return None;
+    }
+
+    /// Return the pinned value, constructing it in place with `new` if it has not been constructed.
+    #[inline]
+    pub fn get_or_emplace<N: New<Output = T>>(&'static self, new: N) -> Pin<&'static T> {
+        match self.get_or_try_emplace(new) {
+            | Ok(pin) => return pin,
+            | Err(err) => match err {},
+        }
+    }
+
+    /// Return the pinned value, trying to construct it in place with `new` if it has not been
+    /// constructed.
+    ///
+    /// If `new` fails (or panics), the [`StaticSlot`] is left uninitialized and a later call may
+    /// try again.
+    ///
+    /// # Errors
+    ///
+    /// Should return `Err` if the `new` initializer fails with an error.
+    #[inline]
+    pub fn get_or_try_emplace<N: TryNew<Output = T>>(
+        &'static self,
+        new: N,
+    ) -> Result<Pin<&'static T>, N::Error> {
+        self.state.call(|| {
+            let memory = unsafe { &mut *self.memory.get() };
+            return unsafe { new.try_new(Pin::new_unchecked(memory)) };
+        })?;
+        return Ok(unsafe { self.get_unchecked() });
+    }
+
+    /// Return the pinned value, assuming it has been constructed.
+    ///
+    /// # Safety
+    ///
+    /// - the state must have been observed as initialized
+    #[inline]
+    unsafe fn get_unchecked(&'static self) -> Pin<&'static T> {
+        let ptr = (*self.memory.get()).assume_init_ref();
+        return Pin::new_unchecked(ptr);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn get_or_emplace() {
+        static SLOT: StaticSlot<u32> = StaticSlot::new();
+        assert!(SLOT.get().is_none());
+        let val = SLOT.get_or_emplace(new::of(42));
+        assert_eq!(42, *val);
+        let val = SLOT.get_or_emplace(new::of(0));
+        assert_eq!(42, *val);
+        assert_eq!(Some(42), SLOT.get().map(|pin| return *pin));
+    }
+
+    #[test]
+    fn get_or_try_emplace_error() {
+        static SLOT: StaticSlot<u32> = StaticSlot::new();
+        assert!(
+            SLOT.get_or_try_emplace(crate::fixtures::Fail::new())
+                .is_err()
+        );
+        assert!(SLOT.get().is_none());
+        assert_eq!(42, *SLOT.get_or_emplace(new::of(42)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn get_or_emplace_concurrent() {
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static SLOT: StaticSlot<u32> = StaticSlot::new();
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        std::thread::scope(|scope| {
+            for _ in 0 .. 8 {
+                scope.spawn(|| {
+                    let new = unsafe {
+                        new::by_raw(|mut this| {
+                            COUNT.fetch_add(1, Ordering::SeqCst);
+                            this.set(core::mem::MaybeUninit::new(42));
+                        })
+                    };
+                    let val = SLOT.get_or_emplace(new);
+                    assert_eq!(42, *val);
+                });
+            }
+        });
+        assert_eq!(1, COUNT.load(Ordering::SeqCst));
+    }
+}
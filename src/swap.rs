@@ -0,0 +1,144 @@
+use core::{mem::MaybeUninit, pin::Pin};
+
+use crate::{
+    move_ref::MoveRef,
+    new::{MoveNew, New},
+    slot::Slot,
+};
+
+/// Helper type for aborting if a move or construction unwinds while a pinned location is
+/// uninitialized, since its owner would otherwise observe or destroy a dead value.
+pub(crate) struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind {
+    #[inline]
+    fn drop(&mut self) {
+        // Panicking while already unwinding aborts the process.
+        panic!("a pinned value was left uninitialized by a panicking move or construction");
+    }
+}
+
+/// Move the value at `src` into `dst` with [`MoveNew`].
+///
+/// # Safety
+///
+/// - `src` must point to a valid `T`, which is no longer valid afterward
+/// - `dst` must be suitable uninitialized storage for a `T`, which is valid afterward
+#[inline]
+unsafe fn relocate<T: MoveNew>(src: *mut T, dst: *mut MaybeUninit<T>) {
+    bind_slot!(storage);
+    let src = MoveRef::from_raw_in(src, storage).into_pin();
+    T::move_new(src, Pin::new_unchecked(&mut *dst));
+}
+
+/// Swap the values of `a` and `b` using [`MoveNew`] rather than a bitwise swap, as with `std::swap`
+/// in C++.
+///
+/// The values are moved through a temporary, so three move-constructions take place.
+///
+/// # Panics
+///
+/// If a move-construction panics, the process is aborted rather than unwinding, since either `a` or
+/// `b` would otherwise be left without a valid value.
+#[inline]
+pub fn swap<T: MoveNew>(a: Pin<&mut T>, b: Pin<&mut T>) {
+    let a = core::ptr::from_mut(unsafe { Pin::into_inner_unchecked(a) });
+    let b = core::ptr::from_mut(unsafe { Pin::into_inner_unchecked(b) });
+    let mut tmp = MaybeUninit::<T>::uninit();
+    let guard = AbortOnUnwind;
+    unsafe {
+        relocate(a, &mut tmp);
+        relocate(b, a.cast::<MaybeUninit<T>>());
+        relocate(tmp.as_mut_ptr(), b.cast::<MaybeUninit<T>>());
+    }
+    core::mem::forget(guard);
+}
+
+/// Replace the value of `dst` with one constructed in place by `new`, moving the previous value into
+/// `slot` with [`MoveNew`] and returning it.
+///
+/// # Panics
+///
+/// If the move-construction or `new` panics, the process is aborted rather than unwinding, since
+/// `dst` would otherwise be left without a valid value.
+#[inline]
+pub fn replace<'frame, T, N>(
+    dst: Pin<&mut T>,
+    new: N,
+    slot: Slot<'frame, T>,
+) -> Pin<MoveRef<'frame, T>>
+where
+    T: MoveNew,
+    N: New<Output = T>,
+{
+    let dst = core::ptr::from_mut(unsafe { Pin::into_inner_unchecked(dst) });
+    let guard = AbortOnUnwind;
+    let old = slot.emplace(unsafe {
+        crate::new::by_raw(|this: Pin<&mut MaybeUninit<T>>| {
+            relocate(dst, core::ptr::from_mut(Pin::into_inner_unchecked(this)));
+        })
+    });
+    unsafe { new.new(Pin::new_unchecked(&mut *dst.cast::<MaybeUninit<T>>())) };
+    core::mem::forget(guard);
+    return old;
+}
+
+#[cfg(test)]
+mod test {
+    use core::{marker::PhantomPinned, mem::MaybeUninit, pin::Pin};
+
+    use crate::*;
+
+    /// A value which records its own address, so that moves can be checked to fix it up.
+    struct Tracked {
+        val: u32,
+        addr: *const Self,
+        _pinned: PhantomPinned,
+    }
+
+    impl Tracked {
+        fn new(val: u32) -> impl New<Output = Self> {
+            unsafe {
+                return new::by_raw(move |this: Pin<&mut MaybeUninit<Self>>| {
+                    let this = Pin::into_inner_unchecked(this);
+                    let addr = this.as_ptr();
+                    this.write(Self {
+                        val,
+                        addr,
+                        _pinned: PhantomPinned,
+                    });
+                });
+            }
+        }
+
+        fn is_fixed(&self) -> bool {
+            return core::ptr::eq(self, self.addr);
+        }
+    }
+
+    impl MoveNew for Tracked {
+        unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+            Self::new(src.val).new(dst);
+        }
+    }
+
+    #[test]
+    fn swap() {
+        bind!(mut a = Tracked::new(1));
+        bind!(mut b = Tracked::new(2));
+        crate::swap(a.as_mut(), b.as_mut());
+        assert_eq!((2, 1), (a.val, b.val));
+        assert!(a.is_fixed());
+        assert!(b.is_fixed());
+    }
+
+    #[test]
+    fn replace() {
+        bind!(mut a = Tracked::new(1));
+        bind_slot!(slot);
+        let old = crate::replace(a.as_mut(), Tracked::new(2), slot);
+        assert_eq!((2, 1), (a.val, old.val));
+        assert!(a.is_fixed());
+        assert!(old.is_fixed());
+    }
+}
@@ -0,0 +1,303 @@
+//! Test support for checking [`MoveNew`] and [`CopyNew`] implementations.
+//!
+//! A type under test holds a [`Probe`], which records when it is constructed and destroyed, and
+//! implements [`Instrumented`] to construct itself around a given [`Probe`]. The conformance checks
+//! [`check_move_new`] and [`check_copy_new`] then move or copy values of the type through
+//! [`Slot`](crate::Slot), [`Emplace`], [`new::mov`], and [`CopyNew`], and panic unless each source
+//! and each destination value was destroyed exactly once.
+//!
+//! ```
+//! # use core::{marker::PhantomPinned, mem::MaybeUninit, pin::Pin};
+//! # use moveref::{new, testing::{self, Instrumented, Probe}, MoveNew, MoveRef, New};
+//! struct Widget {
+//!     probe: Probe,
+//!     _pinned: PhantomPinned,
+//! }
+//!
+//! impl Instrumented for Widget {
+//!     fn instrumented(probe: Probe) -> impl New<Output = Self> {
+//!         return new::of(Widget { probe, _pinned: PhantomPinned });
+//!     }
+//! }
+//!
+//! impl MoveNew for Widget {
+//!     unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+//!         let src = MoveRef::into_inner(Pin::into_inner_unchecked(src));
+//!         Pin::into_inner_unchecked(dst).write(src);
+//!     }
+//! }
+//!
+//! testing::check_move_new::<Widget>();
+//! ```
+
+use core::{cell::RefCell, marker::PhantomData};
+use std::vec::Vec;
+
+use crate::{
+    emplace::Emplace,
+    new::{self, CopyNew, MoveNew, New},
+    Box,
+};
+
+std::thread_local! {
+    /// Number of destructions of each [`Probe`] constructed on the current thread, by id.
+    static DESTROYED: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// An instrumented value which records its constructions and destructions.
+///
+/// A [`Probe`] is only ever created by the conformance checks. Cloning a [`Probe`] records a
+/// construction of a new probe, and dropping one records a destruction of that probe. Moving a
+/// [`Probe`] records nothing.
+pub struct Probe {
+    /// The index of this probe's destruction count.
+    id: usize,
+    /// Marker making the probe `!Send` and `!Sync`, since counts are recorded per thread.
+    _thread: PhantomData<*const ()>,
+}
+
+impl Probe {
+    /// Construct a new [`Probe`], recording a construction.
+    fn new() -> Self {
+        let id = DESTROYED.with_borrow_mut(|counts| {
+            counts.push(0);
+            return counts.len() - 1;
+        });
+        return Self {
+            id,
+            _thread: PhantomData,
+        };
+    }
+}
+
+impl Clone for Probe {
+    #[inline]
+    fn clone(&self) -> Self {
+        return Self::new();
+    }
+}
+
+impl Drop for Probe {
+    #[inline]
+    fn drop(&mut self) {
+        DESTROYED.with_borrow_mut(|counts| counts[self.id] += 1);
+    }
+}
+
+trivial_copy! {
+    Probe,
+}
+
+trivial_move! {
+    Probe,
+}
+
+impl Instrumented for Probe {
+    #[inline]
+    fn instrumented(probe: Probe) -> impl New<Output = Self> {
+        return new::of(probe);
+    }
+}
+
+/// Types which can be constructed around a [`Probe`] for checking conformance.
+///
+/// The constructed value must own `probe`, and must destroy it exactly once when it is destroyed.
+pub trait Instrumented: Sized {
+    /// Construct a value holding `probe`.
+    fn instrumented(probe: Probe) -> impl New<Output = Self>;
+}
+
+/// Run `scenario` for the type `T` with fresh counts and check that every constructed probe was
+/// destroyed exactly once.
+fn check<T>(name: &str, scenario: impl FnOnce(Probe)) {
+    DESTROYED.with_borrow_mut(Vec::clear);
+    scenario(Probe::new());
+    let counts = DESTROYED.take();
+    if let Some((id, count)) = counts
+        .iter()
+        .enumerate()
+        .find(|&(_, &count)| return count != 1)
+    {
+        panic!(
+            "`{}` failed scenario `{name}`: probe {id} of {} was destroyed {count} times",
+            core::any::type_name::<T>(),
+            counts.len(),
+        );
+    }
+}
+
+/// Check that moving values of `T` with [`MoveNew`] destroys each value exactly once.
+///
+/// # Panics
+///
+/// Panics if some value was not destroyed exactly once.
+pub fn check_move_new<T: Instrumented + MoveNew>() {
+    check::<T>("slot", |probe| {
+        bind!(_src = T::instrumented(probe));
+    });
+    check::<T>("slot to slot", |probe| {
+        bind!(src = T::instrumented(probe));
+        bind!(_dst = new::mov(src));
+    });
+    check::<T>("slot to box", |probe| {
+        bind!(src = T::instrumented(probe));
+        let _dst = Box::emplace(new::mov(src));
+    });
+    check::<T>("box", |probe| {
+        let _src = Box::emplace(T::instrumented(probe));
+    });
+    check::<T>("box to slot", |probe| {
+        let src = Box::emplace(T::instrumented(probe));
+        bind!(_dst = new::mov(src));
+    });
+    check::<T>("box to box", |probe| {
+        let src = Box::emplace(T::instrumented(probe));
+        let _dst = Box::emplace(new::mov(src));
+    });
+    check::<T>("slot to slot to slot", |probe| {
+        bind!(src = T::instrumented(probe));
+        bind!(mid = new::mov(src));
+        bind!(_dst = new::mov(mid));
+    });
+}
+
+/// Check that copying values of `T` with [`CopyNew`] constructs a distinct value which is
+/// destroyed exactly once, independently of the source.
+///
+/// # Panics
+///
+/// Panics if some value was not destroyed exactly once.
+pub fn check_copy_new<T: Instrumented + CopyNew>() {
+    check::<T>("slot to slot", |probe| {
+        bind!(src = T::instrumented(probe));
+        bind!(_dst = new::copy(&*src));
+    });
+    check::<T>("slot to box", |probe| {
+        bind!(src = T::instrumented(probe));
+        let _dst = Box::emplace(new::copy(&*src));
+    });
+    check::<T>("box to slot", |probe| {
+        let src = Box::emplace(T::instrumented(probe));
+        bind!(_dst = new::copy(&*src));
+    });
+    check::<T>("box to box", |probe| {
+        let src = Box::emplace(T::instrumented(probe));
+        let _dst = Box::emplace(new::copy(&*src));
+    });
+    check::<T>("slot to dropped slot", |probe| {
+        bind!(src = T::instrumented(probe));
+        {
+            bind!(_dst = new::copy(&*src));
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use core::{marker::PhantomPinned, mem::MaybeUninit, pin::Pin};
+
+    use super::*;
+    use crate::MoveRef;
+
+    struct Pinned {
+        probe: Probe,
+        _pinned: PhantomPinned,
+    }
+
+    impl Instrumented for Pinned {
+        fn instrumented(probe: Probe) -> impl New<Output = Self> {
+            return new::of(Self {
+                probe,
+                _pinned: PhantomPinned,
+            });
+        }
+    }
+
+    impl MoveNew for Pinned {
+        unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+            let src = MoveRef::into_inner(Pin::into_inner_unchecked(src));
+            Pin::into_inner_unchecked(dst).write(src);
+        }
+    }
+
+    impl CopyNew for Pinned {
+        unsafe fn copy_new(src: &Self, dst: Pin<&mut MaybeUninit<Self>>) {
+            Pin::into_inner_unchecked(dst).write(Self {
+                probe: src.probe.clone(),
+                _pinned: PhantomPinned,
+            });
+        }
+    }
+
+    struct DoubleDrop {
+        _probe: Probe,
+    }
+
+    impl Instrumented for DoubleDrop {
+        fn instrumented(probe: Probe) -> impl New<Output = Self> {
+            return new::of(Self { _probe: probe });
+        }
+    }
+
+    impl MoveNew for DoubleDrop {
+        unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+            let val = core::ptr::read(&*src);
+            Pin::into_inner_unchecked(dst).write(val);
+        }
+    }
+
+    impl CopyNew for DoubleDrop {
+        unsafe fn copy_new(src: &Self, dst: Pin<&mut MaybeUninit<Self>>) {
+            Pin::into_inner_unchecked(dst).write(core::ptr::read(src));
+        }
+    }
+
+    struct Leak {
+        _probe: Probe,
+    }
+
+    impl Instrumented for Leak {
+        fn instrumented(probe: Probe) -> impl New<Output = Self> {
+            return new::of(Self { _probe: probe });
+        }
+    }
+
+    impl MoveNew for Leak {
+        unsafe fn move_new(src: Pin<MoveRef<Self>>, dst: Pin<&mut MaybeUninit<Self>>) {
+            let _ = MoveRef::release(src);
+            Pin::into_inner_unchecked(dst).write(Self {
+                _probe: Probe::new(),
+            });
+        }
+    }
+
+    #[test]
+    fn probe() {
+        check_move_new::<Probe>();
+        check_copy_new::<Probe>();
+    }
+
+    #[test]
+    fn pinned() {
+        check_move_new::<Pinned>();
+        check_copy_new::<Pinned>();
+    }
+
+    #[test]
+    #[should_panic(expected = "failed scenario `slot to slot`")]
+    fn move_new_double_drop() {
+        check_move_new::<DoubleDrop>();
+    }
+
+    #[test]
+    #[should_panic(expected = "failed scenario `slot to slot`")]
+    fn copy_new_double_drop() {
+        check_copy_new::<DoubleDrop>();
+    }
+
+    #[test]
+    #[should_panic(expected = "failed scenario `slot to slot`")]
+    fn move_new_leak() {
+        check_move_new::<Leak>();
+    }
+}
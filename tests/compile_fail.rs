@@ -0,0 +1,8 @@
+#[cfg_attr(miri, ignore)]
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+    #[cfg(feature = "macros")]
+    cases.compile_fail("tests/ui-macros/*.rs");
+}
@@ -0,0 +1,8 @@
+use core::{mem::MaybeUninit, pin::Pin};
+
+#[moveref::ctor]
+fn make(this: Pin<&mut MaybeUninit<u32>>) {
+    let _ = this;
+}
+
+fn main() {}
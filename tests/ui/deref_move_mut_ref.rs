@@ -0,0 +1,8 @@
+use moveref::bind;
+
+fn main() {
+    let mut val = 42;
+    let ptr = &mut val;
+    bind!(x = &move *ptr);
+    assert_eq!(42, *x);
+}
@@ -0,0 +1,6 @@
+use moveref::expr;
+
+fn main() {
+    let x = expr!(&move 42);
+    assert_eq!(42, *x);
+}
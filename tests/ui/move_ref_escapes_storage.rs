@@ -0,0 +1,10 @@
+use moveref::{bind, MoveRef};
+
+fn escape() -> MoveRef<'static, i32> {
+    bind!(x = &move 42);
+    return x;
+}
+
+fn main() {
+    let _ = escape();
+}
@@ -0,0 +1,13 @@
+use core::{marker::PhantomPinned, pin::Pin};
+
+use moveref::{bind, new, MoveRef};
+
+struct Pinned {
+    _pinned: PhantomPinned,
+}
+
+fn main() {
+    bind!(x = new::of(Pinned { _pinned: PhantomPinned }));
+    let x: MoveRef<Pinned> = Pin::into_inner(x);
+    let _: Pinned = MoveRef::into_inner(x);
+}
@@ -0,0 +1,7 @@
+use moveref::bind_slot;
+
+fn main() {
+    bind_slot!(slot);
+    let _x = slot.put(1);
+    let _y = slot.put(2);
+}
@@ -0,0 +1,7 @@
+use std::rc::Rc;
+
+use moveref::{new, TryAllocEmplace};
+
+fn main() {
+    let _ = <Rc<_> as TryAllocEmplace<_>>::try_alloc_emplace(new::of(42));
+}